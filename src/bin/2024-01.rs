@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2024, 1);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let (mut first, mut second, size) = parse_2_column_input_same_size::<u32>(input);
@@ -79,13 +79,18 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(31));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2024, 2);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let (data, _) = parse_row_input::<u32>(input);
@@ -133,13 +133,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(7));
     }
 
@@ -163,4 +163,9 @@ mod tests {
         let trimmed = remove_element(&data, 4);
         assert_eq!(trimmed, [1, 2, 3, 4,])
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let result = state_machine_parser(input);
@@ -154,7 +154,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(161));
     }
@@ -162,7 +162,7 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(48));
     }
@@ -225,4 +225,9 @@ mod tests {
         let result = state_machine_parser_with_do(incomplete_garbage_dont);
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,14 +1,18 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(4);
+use advent_of_code::template::span;
+
+advent_of_code::solution!(2024, 4);
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let matrix = Matrix { size, data };
+    let ((matrix, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        (Matrix { size, data }, size)
+    });
 
     let mut acc = 0;
-    
+
     for y in 0..size.y {
         for x in 0..size.x {
             //print!("{}", matrix[y][x]);
@@ -21,8 +25,10 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let matrix = Matrix { size, data };
+    let ((matrix, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        (Matrix { size, data }, size)
+    });
 
     let mut acc = 0;
 
@@ -236,7 +242,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(18));
     }
@@ -244,8 +250,13 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(9));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,8 +1,9 @@
+use advent_of_code::template::input::sections;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2024, 5);
 
 pub fn part_one(input: &str) -> Option<u32> {
     solve(input, &Day5::Part1)
@@ -18,11 +19,7 @@ enum Day5 {
 }
 
 fn solve(input: &str, part: &Day5) -> Option<u32> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let graph_data = data[0];
     let sort_data = data[1];
 
@@ -222,7 +219,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(143));
     }
@@ -230,7 +227,7 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(123));
     }
@@ -238,8 +235,13 @@ mod tests {
     #[test]
     fn test_part_two_debugging() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(78));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -4,11 +4,16 @@ use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
-advent_of_code::solution!(6);
+use advent_of_code::template::span;
+
+advent_of_code::solution!(2024, 6, variants { 2 => [part_two_st, part_two_mt] });
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let guard_position_index = data_cells
         .iter()
@@ -48,8 +53,11 @@ pub fn part_two(input: &str) -> Option<u32> {
 pub fn part_two_st(input: &str) -> Option<u32> {
     // Single thread version.
 
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let guard_position_index = data_cells
         .iter()
@@ -86,13 +94,11 @@ pub fn part_two_st(input: &str) -> Option<u32> {
         .filter(|(index, c)| c.have_guard_visited && *index != guard_position_index);
 
     let mut loop_counter = 0;
-    let mut iter_counter = 1;
 
-    for (index, change_cell) in possible_block_locations {
+    for (iter_counter, (index, change_cell)) in (1..).zip(possible_block_locations) {
         if cfg!(debug_assertions) {
             println!("Solving [{} / {}]", iter_counter, total - 1); // -1 for starting pos
         }
-        iter_counter += 1;
 
         // create new state set for collision detection
         let mut guard_state_set: HashSet<(Index, Direction)> = HashSet::new();
@@ -139,8 +145,11 @@ pub fn part_two_mt(input: &str) -> Option<u32> {
     // Rewrite of part 2 with multithread with a help of gpt.
     // (I don't know rust multithreading yet...)
 
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let guard_position_index = data_cells
         .iter()
@@ -500,25 +509,30 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two_st() {
-        let result = part_two_st(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two_st(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two_mt() {
-        let result = part_two_mt(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two_mt(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(2024, 7);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let data = parse_input(input);
@@ -262,13 +262,18 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11387));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
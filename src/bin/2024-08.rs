@@ -3,11 +3,16 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(8);
+use advent_of_code::template::span;
+
+advent_of_code::solution!(2024, 8);
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         size,
@@ -75,8 +80,11 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         size,
@@ -273,7 +281,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(14));
     }
@@ -281,7 +289,7 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(34));
     }
@@ -289,8 +297,13 @@ mod tests {
     #[test]
     fn test_part_two_dbg() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(9));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
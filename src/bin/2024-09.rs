@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(2024, 9);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let compressed_form = parse_compressed_form(input);
@@ -69,13 +69,11 @@ fn compress(sparse_form: &mut Vec<i64>) {
 
         // get last non-free element
         let mut last_element = -1;
-        let mut pop_index = 0;
         while last_element == -1 {
             last_element = sparse_form.pop().unwrap();
-            pop_index += 1;
         }
 
-        if sparse_form.len() - pop_index <= index {
+        if sparse_form.len() <= index {
             sparse_form.push(last_element); // push back
             break;
         }
@@ -203,7 +201,7 @@ mod tests {
     #[test]
     fn test_part_one_dbg() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(60));
     }
@@ -211,7 +209,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(1928));
     }
@@ -226,8 +224,13 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(2858));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -2,11 +2,16 @@ use std::collections::HashSet;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(10);
+use advent_of_code::template::span;
+
+advent_of_code::solution!(2024, 10);
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         size,
@@ -45,8 +50,11 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     // same as part 1, but different dfs.
 
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         size,
@@ -392,13 +400,18 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(36));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(81));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
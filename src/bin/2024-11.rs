@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2024, 11, variants { 1 => [part_one_counting] });
 
 pub fn part_one(input: &str) -> Option<u64> {
     const TOTAL_BLINKS: u32 = 25;
@@ -14,6 +14,13 @@ pub fn part_two(input: &str) -> Option<u128> {
     part_two_blinks(input, TOTAL_BLINKS)
 }
 
+/// Part one, counting stones by value like part two instead of creating every stone.
+pub fn part_one_counting(input: &str) -> Option<u128> {
+    const TOTAL_BLINKS: u32 = 25;
+
+    part_two_blinks(input, TOTAL_BLINKS)
+}
+
 pub fn part_one_blinks(input: &str, blinks: u32) -> Option<u64> {
     // Just create the vec of stones and compute them.
 
@@ -49,7 +56,7 @@ pub fn part_two_blinks(input: &str, blinks: u32) -> Option<u128> {
     }
 
     let result = count.values().sum();
-    
+
     Some(result)
 }
 
@@ -125,25 +132,42 @@ mod tests {
 
     #[test]
     fn test_part_one_blinks_6() {
-        let result = part_one_blinks(&advent_of_code::template::read_file("examples", DAY), 6);
+        let result = part_one_blinks(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            6,
+        );
         assert_eq!(result, Some(22));
     }
 
     #[test]
     fn test_part_one_blinks_25() {
-        let result = part_one_blinks(&advent_of_code::template::read_file("examples", DAY), 25);
+        let result = part_one_blinks(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            25,
+        );
         assert_eq!(result, Some(55312));
     }
 
     #[test]
     fn test_part_two_blinks_6() {
-        let result = part_two_blinks(&advent_of_code::template::read_file("examples", DAY), 6);
+        let result = part_two_blinks(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            6,
+        );
         assert_eq!(result, Some(22));
     }
 
     #[test]
     fn test_part_two_blinks_25() {
-        let result = part_two_blinks(&advent_of_code::template::read_file("examples", DAY), 25);
+        let result = part_two_blinks(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            25,
+        );
         assert_eq!(result, Some(55312));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,15 +1,20 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(12);
+use advent_of_code::template::span;
+
+advent_of_code::solution!(2024, 12);
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data
-        .into_iter()
-        .enumerate()
-        .map(|(i, t)| MapCell::new(i, t))
-        .collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| MapCell::new(i, t))
+            .collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         data: data_cells.clone(),
@@ -41,12 +46,15 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data
-        .into_iter()
-        .enumerate()
-        .map(|(i, t)| MapCell::new(i, t))
-        .collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| MapCell::new(i, t))
+            .collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         data: data_cells.clone(),
@@ -486,7 +494,7 @@ mod tests {
     #[test]
     fn test_part_one_1() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(140));
     }
@@ -494,7 +502,7 @@ mod tests {
     #[test]
     fn test_part_one_2() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(772));
     }
@@ -502,7 +510,7 @@ mod tests {
     #[test]
     fn test_part_one_3() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", YEAR, DAY, 3,
         ));
         assert_eq!(result, Some(1930));
     }
@@ -510,7 +518,7 @@ mod tests {
     #[test]
     fn test_part_two_1() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(80));
     }
@@ -518,7 +526,7 @@ mod tests {
     #[test]
     fn test_part_two_2() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(436));
     }
@@ -526,7 +534,7 @@ mod tests {
     #[test]
     fn test_part_two_3() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", YEAR, DAY, 3,
         ));
         assert_eq!(result, Some(1206));
     }
@@ -534,7 +542,7 @@ mod tests {
     #[test]
     fn test_part_two_4() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
+            "examples", YEAR, DAY, 4,
         ));
         assert_eq!(result, Some(236));
     }
@@ -542,8 +550,13 @@ mod tests {
     #[test]
     fn test_part_two_5() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 5,
+            "examples", YEAR, DAY, 5,
         ));
         assert_eq!(result, Some(368));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
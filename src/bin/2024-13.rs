@@ -1,6 +1,6 @@
 use std::ops::{Div, Mul, Sub};
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2024, 13);
 
 #[allow(non_snake_case)]
 pub fn part_one(input: &str) -> Option<u64> {
@@ -215,13 +215,18 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(875318608908));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2024, 14);
 
 pub fn part_one(input: &str) -> Option<u32> {
     const MAP_SIZE: Index = Index { x: 101, y: 103 };
@@ -289,7 +289,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = solve_part_1(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             Index { x: 11, y: 7 },
         );
         assert_eq!(result, Some(12));
@@ -297,7 +297,12 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,15 +1,12 @@
+use advent_of_code::template::input::sections;
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, 15);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let map_data = data[0];
     let commands_data = data[1];
 
@@ -42,11 +39,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let map_data = data[0];
     let commands_data = data[1];
 
@@ -623,7 +616,7 @@ mod tests {
     #[test]
     fn test_part_one_1() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(2028));
     }
@@ -631,7 +624,7 @@ mod tests {
     #[test]
     fn test_part_one_2() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(10092));
     }
@@ -639,7 +632,7 @@ mod tests {
     #[test]
     fn test_part_two_3() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", YEAR, DAY, 3,
         ));
         assert_eq!(result, Some(618));
     }
@@ -647,8 +640,13 @@ mod tests {
     #[test]
     fn test_part_two_2() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(9021));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
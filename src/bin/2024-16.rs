@@ -5,11 +5,16 @@ use std::str::FromStr;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-advent_of_code::solution!(16);
+use advent_of_code::template::span;
+
+advent_of_code::solution!(2024, 16);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         size,
@@ -46,8 +51,11 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (data, size) = parse_row_input_as_data_array::<char>(input);
-    let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+    let ((data_cells, size), _solve) = span::parse_then_solve(|| {
+        let (data, size) = parse_row_input_as_data_array::<char>(input);
+        let data_cells: Vec<MapCell> = data.into_iter().map(MapCell::from).collect();
+        (data_cells, size)
+    });
 
     let mut matrix = Matrix {
         size,
@@ -499,7 +507,7 @@ mod tests {
     #[test]
     fn test_part_one_1() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(7036));
     }
@@ -507,7 +515,7 @@ mod tests {
     #[test]
     fn test_part_one_2() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(11048));
     }
@@ -515,7 +523,7 @@ mod tests {
     #[test]
     fn test_part_two_1() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(45));
     }
@@ -523,8 +531,13 @@ mod tests {
     #[test]
     fn test_part_two_2() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(64));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,11 +1,9 @@
-advent_of_code::solution!(17);
+use advent_of_code::template::input::sections;
+
+advent_of_code::solution!(2024, 17);
 
 pub fn part_one(input: &str) -> Option<String> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let machine_data = data[0];
     let commands_data = data[1];
 
@@ -37,11 +35,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     // At each step A = A / 8
     // So A can be brute forced byte by byte from the end.
 
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let machine_data = data[0];
     let commands_data = data[1];
 
@@ -101,11 +95,7 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 pub fn part_two_slow(input: &str) -> Option<u64> {
     // too slow.
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let machine_data = data[0];
     let commands_data = data[1];
 
@@ -395,7 +385,7 @@ mod tests {
     #[test]
     fn test_part_one_1() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_string()));
     }
@@ -403,7 +393,7 @@ mod tests {
     #[test]
     fn test_part_one_2() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some("0,3,5,4,3,0".to_string()));
     }
@@ -411,7 +401,7 @@ mod tests {
     #[test]
     fn test_part_two_2_slow() {
         let result = part_two_slow(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(117440));
     }
@@ -419,8 +409,13 @@ mod tests {
     #[test]
     fn test_part_two_2() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(117440));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -3,7 +3,7 @@ use std::collections::BinaryHeap;
 use std::fmt::Debug;
 use strum_macros::EnumIter;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2024, 18);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let size = Size { x: 71, y: 71 };
@@ -101,11 +101,9 @@ fn solve_part_2(input: &str, size: Size, bytes_number_start: usize) -> Option<St
         to_visit_set = BinaryHeap::new();
         to_visit_set.push(Reverse((0_u64, start_index)));
 
-        // Place bytes up to the `mid` index.
-        #[allow(clippy::needless_range_loop)]
-        for i in 0..=next_byte_index {
-            let byte_pos = bytes_fall[i];
-            matrix[byte_pos.y][byte_pos.x].has_byte = true;
+        // Place bytes up to the `mid` index, removing the ones of a previous, larger `mid`.
+        for (i, byte_pos) in bytes_fall.iter().enumerate() {
+            matrix[byte_pos.y][byte_pos.x].has_byte = i <= next_byte_index;
         }
 
         let result = pseudo_dijkstra(&mut matrix, &end_index, &mut to_visit_set);
@@ -117,9 +115,8 @@ fn solve_part_2(input: &str, size: Size, bytes_number_start: usize) -> Option<St
         //println!();
     }
 
-    // need the first "bad" index, so +1
-    // as low is the last "good" one.
-    let byte_pos = bytes_fall[low + 1];
+    // low is the first "bad" index, the byte that cuts off the exit. No byte may ever cut it off.
+    let byte_pos = bytes_fall.get(low)?;
 
     Some(format!("{},{}", byte_pos.x, byte_pos.y))
 }
@@ -415,7 +412,7 @@ mod tests {
     fn test_part_one_1() {
         let size = Size { x: 7, y: 7 };
         let result = solve_part_1(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             size,
             12,
         );
@@ -426,7 +423,7 @@ mod tests {
     fn test_part_one_2() {
         let size = Size { x: 7, y: 7 };
         let result = solve_part_1(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             size,
             19,
         );
@@ -437,10 +434,24 @@ mod tests {
     fn test_part_two() {
         let size = Size { x: 7, y: 7 };
         let result = solve_part_2(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             size,
             12,
         );
         assert_eq!(result, Some("6,1".to_string()));
     }
+
+    #[test]
+    fn test_part_two_never_blocked() {
+        let size = Size { x: 7, y: 7 };
+        let input = advent_of_code::template::read_file("examples", YEAR, DAY);
+        let first_bytes: Vec<&str> = input.lines().take(12).collect();
+        let result = solve_part_2(&first_bytes.join("\n"), size, 12);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
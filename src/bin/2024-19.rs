@@ -1,15 +1,12 @@
+use advent_of_code::template::input::sections;
 use std::collections::HashMap;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2024, 19);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let patterns_data = data[0];
     let targets_data = data[1];
 
@@ -26,15 +23,11 @@ pub fn part_one(input: &str) -> Option<u64> {
 
     //dbg!(result);
 
-    Some(result as u64)
+    Some(result)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let patterns_data = data[0];
     let targets_data = data[1];
 
@@ -51,7 +44,7 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     //dbg!(result);
 
-    Some(result as u64)
+    Some(result)
 }
 
 fn is_target_possible(target: &str, patterns: &HashMap<usize, Vec<String>>) -> bool {
@@ -131,13 +124,18 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(16));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
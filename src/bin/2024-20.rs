@@ -5,7 +5,7 @@ use std::str::FromStr;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2024, 20);
 
 pub fn part_one(input: &str) -> Option<u64> {
     solve_part_one(input, 100)
@@ -514,26 +514,35 @@ mod tests {
 
     #[test]
     fn test_part_one_1() {
-        let result = solve_part_one(&advent_of_code::template::read_file("examples", DAY), 20);
+        let result = solve_part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            20,
+        );
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_one_2() {
-        let result = solve_part_one(&advent_of_code::template::read_file("examples", DAY), 2);
+        let result = solve_part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            2,
+        );
         assert_eq!(result, Some(44));
     }
 
     #[test]
     fn test_part_one_3() {
-        let result = solve_part_one(&advent_of_code::template::read_file("examples", DAY), 64);
+        let result = solve_part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            64,
+        );
         assert_eq!(result, Some(1));
     }
 
     #[test]
     fn test_part_two_1() {
         let result = solve_part_two(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             76,
             20,
         );
@@ -543,7 +552,7 @@ mod tests {
     #[test]
     fn test_part_two_2() {
         let result = solve_part_two(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             74,
             20,
         );
@@ -553,7 +562,7 @@ mod tests {
     #[test]
     fn test_part_two_3() {
         let result = solve_part_two(
-            &advent_of_code::template::read_file("examples", DAY),
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
             50,
             20,
         );
@@ -562,7 +571,16 @@ mod tests {
 
     #[test]
     fn test_part_two_4() {
-        let result = solve_part_two(&advent_of_code::template::read_file("examples", DAY), 50, 4);
+        let result = solve_part_two(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            50,
+            4,
+        );
         assert_eq!(result, Some(12));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
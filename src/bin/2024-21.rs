@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2024, 21);
 
 pub fn part_one(input: &str) -> Option<u64> {
     // huge thanks to https://www.reddit.com/r/adventofcode/comments/1hj2odw/comment/m34tgje
@@ -50,7 +50,7 @@ pub fn part_two_robots(input: &str, robots: u64) -> Option<u64> {
         let numeric: u64 = numeric_part_string
             .parse()
             .expect("Error parsing numeric part");
-        let result = (result_robots as u64) * numeric;
+        let result = result_robots * numeric;
         acc += result;
     }
 
@@ -396,13 +396,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(126384));
     }
 
     #[test]
     fn test_part_two_same_as_part_1() {
-        let result = part_two_robots(&advent_of_code::template::read_file("examples", DAY), 2);
+        let result = part_two_robots(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            2,
+        );
         assert_eq!(result, Some(126384));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
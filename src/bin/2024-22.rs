@@ -4,7 +4,7 @@ use std::{
     thread,
 };
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2024, 22, variants { 2 => [part_two_single_thread] });
 
 pub fn part_one(input: &str) -> Option<u64> {
     let mut secrets: Vec<u64> = Vec::new();
@@ -257,7 +257,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(37327623));
     }
@@ -265,7 +265,7 @@ mod tests {
     #[test]
     fn test_part_two_single_thread() {
         let result = part_two_single_thread(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(23));
     }
@@ -273,8 +273,13 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(23));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
@@ -1,8 +1,9 @@
+use advent_of_code::template::input::sections;
 use std::collections::HashMap;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2024, 24);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let mut circuit = parse_circuit(input);
@@ -59,11 +60,7 @@ pub fn part_two(input: &str) -> Option<String> {
 }
 
 fn parse_circuit(input: &str) -> Circuit {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
     let operand_data = data[0];
     let operation_data = data[1];
 
@@ -214,7 +211,7 @@ mod tests {
     #[test]
     fn test_part_one_1() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(4));
     }
@@ -222,7 +219,7 @@ mod tests {
     #[test]
     fn test_part_one_2() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(2024));
     }
@@ -231,7 +228,7 @@ mod tests {
     fn test_part_two_1() {
         // correctly working
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", YEAR, DAY, 3,
         ));
         assert_eq!(result, Some("".to_string()));
     }
@@ -240,8 +237,13 @@ mod tests {
     fn test_part_two_2() {
         // one swap
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
+            "examples", YEAR, DAY, 4,
         ));
         assert_eq!(result, Some("ccc,z01".to_string()));
     }
+
+    #[test]
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
+    }
 }
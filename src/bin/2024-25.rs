@@ -1,14 +1,11 @@
+use advent_of_code::template::input::sections;
 use std::fmt::Debug;
 use std::str::FromStr;
 
-advent_of_code::solution!(25);
+advent_of_code::solution!(2024, 25, 1);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut data: Vec<&str> = input.split("\r\n\r\n").collect();
-    if data.len() < 2 {
-        // Actual data split.
-        data = input.split("\n\n").collect();
-    }
+    let data: Vec<&str> = sections(input).collect();
 
     let mut locks = Vec::<Lock>::new();
     let mut keys = Vec::<Key>::new();
@@ -32,10 +29,6 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(result)
 }
 
-pub fn part_two(_input: &str) -> Option<u64> {
-    None
-}
-
 enum Data {
     KeyData(Key),
     LockData(Lock),
@@ -140,13 +133,12 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    fn test_generated_input() {
+        advent_of_code::template::generator::check_solution(&SOLUTION);
     }
 }
@@ -0,0 +1,43 @@
+//! Input generators of all days, compiled into the library so `cargo generate` and tests can use them.
+//! Every module defines a `GENERATOR` for its day, new days are registered here.
+use crate::template::generator::Generator;
+
+macro_rules! register {
+    ($($module:ident => $path:literal,)*) => {
+        $(
+            #[path = $path]
+            pub mod $module;
+        )*
+
+        /// All registered generators, ordered by year and day.
+        pub static GENERATORS: &[Generator] = &[$($module::GENERATOR,)*];
+    };
+}
+
+register! {
+    y2024_day01 => "generators/2024-01.rs",
+    y2024_day02 => "generators/2024-02.rs",
+    y2024_day03 => "generators/2024-03.rs",
+    y2024_day04 => "generators/2024-04.rs",
+    y2024_day05 => "generators/2024-05.rs",
+    y2024_day06 => "generators/2024-06.rs",
+    y2024_day07 => "generators/2024-07.rs",
+    y2024_day08 => "generators/2024-08.rs",
+    y2024_day09 => "generators/2024-09.rs",
+    y2024_day10 => "generators/2024-10.rs",
+    y2024_day11 => "generators/2024-11.rs",
+    y2024_day12 => "generators/2024-12.rs",
+    y2024_day13 => "generators/2024-13.rs",
+    y2024_day14 => "generators/2024-14.rs",
+    y2024_day15 => "generators/2024-15.rs",
+    y2024_day16 => "generators/2024-16.rs",
+    y2024_day17 => "generators/2024-17.rs",
+    y2024_day18 => "generators/2024-18.rs",
+    y2024_day19 => "generators/2024-19.rs",
+    y2024_day20 => "generators/2024-20.rs",
+    y2024_day21 => "generators/2024-21.rs",
+    y2024_day22 => "generators/2024-22.rs",
+    y2024_day23 => "generators/2024-23.rs",
+    y2024_day24 => "generators/2024-24.rs",
+    y2024_day25 => "generators/2024-25.rs",
+}
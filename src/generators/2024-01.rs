@@ -0,0 +1,59 @@
+//! Day 1: two columns of location ids. Ids of the right list are often repeated from the left list.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(1),
+    generator: &LocationLists,
+};
+
+/// The size is the number of lines.
+struct LocationLists;
+
+impl InputGenerator for LocationLists {
+    fn default_size(&self) -> usize {
+        1000
+    }
+
+    fn test_size(&self) -> usize {
+        100
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let left: Vec<u64> = (0..size).map(|_| rng.range(10000..100000)).collect();
+        let right: Vec<u64> = (0..size)
+            .map(|_| {
+                if rng.chance(0.3) {
+                    *rng.choose(&left)
+                } else {
+                    rng.range(10000..100000)
+                }
+            })
+            .collect();
+
+        let input = left
+            .iter()
+            .zip(&right)
+            .map(|(l, r)| format!("{l}   {r}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (mut sorted_left, mut sorted_right) = (left.clone(), right.clone());
+        sorted_left.sort_unstable();
+        sorted_right.sort_unstable();
+        let distance: u64 = sorted_left
+            .iter()
+            .zip(&sorted_right)
+            .map(|(l, r)| l.abs_diff(*r))
+            .sum();
+        let similarity: u64 = left
+            .iter()
+            .map(|l| l * right.iter().filter(|r| *r == l).count() as u64)
+            .sum();
+
+        GeneratedInput::new(input)
+            .with_answer(1, distance)
+            .with_answer(2, similarity)
+    }
+}
@@ -0,0 +1,91 @@
+//! Day 2: reports of levels. Most reports are monotonic, some of them with a single bad level.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(2),
+    generator: &Reports,
+};
+
+/// The size is the number of reports.
+struct Reports;
+
+impl InputGenerator for Reports {
+    fn default_size(&self) -> usize {
+        1000
+    }
+
+    fn test_size(&self) -> usize {
+        100
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let reports: Vec<Vec<u64>> = (0..size)
+            .map(|_| {
+                let mut levels = safe_report(rng);
+
+                // a report with a single bad level can be fixed by removing it.
+                let faults = *rng.choose(&[0, 1, 1, 2, 3]);
+                for _ in 0..faults {
+                    let index = rng.index(levels.len());
+                    levels[index] = rng.range(1..100);
+                }
+                levels
+            })
+            .collect();
+
+        let input = reports
+            .iter()
+            .map(|levels| {
+                levels
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // faults may keep a report safe, so both counts are checked level by level.
+        let safe = reports.iter().filter(|levels| is_safe(levels)).count();
+        let dampened = reports
+            .iter()
+            .filter(|levels| {
+                (0..levels.len()).any(|skip| {
+                    let mut levels = levels.to_vec();
+                    levels.remove(skip);
+                    is_safe(&levels)
+                })
+            })
+            .count();
+
+        GeneratedInput::new(input)
+            .with_answer(1, safe)
+            .with_answer(2, dampened)
+    }
+}
+
+/// Whether the levels change monotonically by 1 to 3 per level.
+fn is_safe(levels: &[u64]) -> bool {
+    let increasing = levels.windows(2).all(|w| w[0] < w[1] && w[1] - w[0] <= 3);
+    let decreasing = levels.windows(2).all(|w| w[0] > w[1] && w[0] - w[1] <= 3);
+    increasing || decreasing
+}
+
+/// A report that changes monotonically by 1 to 3 per level.
+fn safe_report(rng: &mut Rng) -> Vec<u64> {
+    let len = rng.range(5..9) as usize;
+    let steps: Vec<u64> = (1..len).map(|_| rng.range(1..4)).collect();
+    let rise: u64 = steps.iter().sum();
+    let start = rng.range(1..100 - rise);
+
+    let mut levels = vec![start];
+    for step in steps {
+        levels.push(levels[levels.len() - 1] + step);
+    }
+    if rng.chance(0.5) {
+        levels.reverse();
+    }
+    levels
+}
@@ -0,0 +1,110 @@
+//! Day 3: corrupted memory with `mul(a,b)` instructions, `do()` and `don't()` between garbage.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(3),
+    generator: &CorruptedMemory,
+};
+
+/// Instructions per line, close to the puzzle input.
+const INSTRUCTIONS_PER_LINE: usize = 120;
+
+/// Garbage never forms an instruction, not even next to other garbage, so the answers follow from the instructions.
+const GARBAGE: &[&str] = &[
+    "!",
+    "@",
+    "#",
+    "$",
+    "%",
+    "^",
+    "&",
+    "*",
+    "(",
+    ")",
+    "[",
+    "]",
+    "{",
+    "}",
+    "<",
+    ">",
+    ",",
+    "?",
+    ";",
+    ":",
+    "'",
+    "+",
+    "-",
+    "_",
+    " ",
+    "/",
+    "~",
+    "what()",
+    "from()",
+    "select()",
+    "who()",
+    "where()",
+    "how()",
+    "when()",
+    "why()",
+    "mul",
+    "mul(",
+    "do ()",
+    "don_t()",
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+];
+
+/// The size is the number of lines.
+struct CorruptedMemory;
+
+impl InputGenerator for CorruptedMemory {
+    fn default_size(&self) -> usize {
+        6
+    }
+
+    fn test_size(&self) -> usize {
+        2
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let (mut sum, mut enabled_sum, mut enabled) = (0, 0, true);
+
+        let input = (0..size)
+            .map(|_| {
+                let mut line = String::new();
+                for _ in 0..INSTRUCTIONS_PER_LINE {
+                    for _ in 0..rng.range(1..8) {
+                        line.push_str(rng.choose::<&str>(GARBAGE));
+                    }
+                    match rng.range(0..10) {
+                        0 => {
+                            line.push_str("do()");
+                            enabled = true;
+                        }
+                        1 => {
+                            line.push_str("don't()");
+                            enabled = false;
+                        }
+                        _ => {
+                            let (a, b) = (rng.range(1..1000), rng.range(1..1000));
+                            line.push_str(&format!("mul({a},{b})"));
+                            sum += a * b;
+                            if enabled {
+                                enabled_sum += a * b;
+                            }
+                        }
+                    }
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        GeneratedInput::new(input)
+            .with_answer(1, sum)
+            .with_answer(2, enabled_sum)
+    }
+}
@@ -0,0 +1,91 @@
+//! Day 4: a square word search made of the letters of `XMAS`.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(4),
+    generator: &WordSearch,
+};
+
+/// The size is the side length of the grid.
+struct WordSearch;
+
+impl InputGenerator for WordSearch {
+    fn default_size(&self) -> usize {
+        140
+    }
+
+    fn test_size(&self) -> usize {
+        30
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let grid: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| *rng.choose(&['X', 'M', 'A', 'S']))
+                    .collect()
+            })
+            .collect();
+
+        let input = grid
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        GeneratedInput::new(input)
+            .with_answer(1, count_xmas(&grid))
+            .with_answer(2, count_x_mas(&grid))
+    }
+}
+
+/// Reads the letter at `(y, x)`, `None` outside of the grid.
+fn letter(grid: &[Vec<char>], y: isize, x: isize) -> Option<char> {
+    let row = grid.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+/// Counts `XMAS` in all eight directions.
+fn count_xmas(grid: &[Vec<char>]) -> usize {
+    let size = grid.len() as isize;
+    let mut count = 0;
+    for y in 0..size {
+        for x in 0..size {
+            for (dy, dx) in [
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ] {
+                let is_xmas = "XMAS".chars().enumerate().all(|(i, c)| {
+                    letter(grid, y + dy * i as isize, x + dx * i as isize) == Some(c)
+                });
+                count += usize::from(is_xmas);
+            }
+        }
+    }
+    count
+}
+
+/// Counts the `A`s that are the center of two diagonal `MAS`.
+fn count_x_mas(grid: &[Vec<char>]) -> usize {
+    let size = grid.len() as isize;
+    let is_mas = |a: Option<char>, b: Option<char>| {
+        matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+    };
+
+    (0..size)
+        .flat_map(|y| (0..size).map(move |x| (y, x)))
+        .filter(|(y, x)| {
+            letter(grid, *y, *x) == Some('A')
+                && is_mas(letter(grid, y - 1, x - 1), letter(grid, y + 1, x + 1))
+                && is_mas(letter(grid, y - 1, x + 1), letter(grid, y + 1, x - 1))
+        })
+        .count()
+}
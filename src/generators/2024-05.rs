@@ -0,0 +1,76 @@
+//! Day 5: page ordering rules for every pair of pages, followed by updates in and out of order.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(5),
+    generator: &PrintQueue,
+};
+
+/// Number of distinct pages, the rules contain a rule for every pair of them.
+const PAGES: usize = 49;
+
+/// The size is the number of updates.
+struct PrintQueue;
+
+impl InputGenerator for PrintQueue {
+    fn default_size(&self) -> usize {
+        200
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        // pages are listed in the order the rules define.
+        let mut pages: Vec<u64> = (10..100).collect();
+        rng.shuffle(&mut pages);
+        pages.truncate(PAGES);
+
+        let mut rules = vec![];
+        for i in 0..PAGES {
+            for j in i + 1..PAGES {
+                rules.push(format!("{}|{}", pages[i], pages[j]));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let (mut ordered, mut reordered) = (0, 0);
+
+        let updates: Vec<String> = (0..size)
+            .map(|_| {
+                let len = 2 * rng.range(2..12) as usize + 1;
+                let mut order: Vec<usize> = (0..PAGES).collect();
+                rng.shuffle(&mut order);
+                order.truncate(len);
+
+                if rng.chance(0.5) {
+                    order.sort_unstable();
+                }
+
+                // the indexes of the pages are their order, so the middle page of an update is known.
+                let mut sorted = order.clone();
+                sorted.sort_unstable();
+                let middle = pages[sorted[len / 2]];
+                if sorted == order {
+                    ordered += middle;
+                } else {
+                    reordered += middle;
+                }
+
+                order
+                    .iter()
+                    .map(|i| pages[*i].to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect();
+
+        let input = format!("{}\n\n{}", rules.join("\n"), updates.join("\n"));
+        GeneratedInput::new(input)
+            .with_answer(1, ordered)
+            .with_answer(2, reordered)
+    }
+}
@@ -0,0 +1,94 @@
+//! Day 6: a lab map with obstacles and a guard facing up, who leaves the map after a long walk.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(6),
+    generator: &LabMap,
+};
+
+/// Maps drawn per input, the one with the longest walk is used.
+const ATTEMPTS: usize = 32;
+
+/// The size is the side length of the map.
+struct LabMap;
+
+impl InputGenerator for LabMap {
+    fn default_size(&self) -> usize {
+        130
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let size = size.max(2);
+        let mut best: Option<(usize, Vec<Vec<char>>)> = None;
+
+        // the guard must not walk in a loop on the original map, so these maps are discarded.
+        while best.is_none() {
+            for _ in 0..ATTEMPTS {
+                let mut map: Vec<Vec<char>> = (0..size)
+                    .map(|_| {
+                        (0..size)
+                            .map(|_| if rng.chance(0.05) { '#' } else { '.' })
+                            .collect()
+                    })
+                    .collect();
+
+                let (y, x) = (rng.index(size), rng.index(size));
+                map[y][x] = '^';
+
+                if let Some(positions) = walk(&map, (y, x)) {
+                    if best.as_ref().is_none_or(|(best, _)| positions > *best) {
+                        best = Some((positions, map));
+                    }
+                }
+            }
+        }
+
+        let (positions, map) = best.unwrap();
+        let input = map
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        GeneratedInput::new(input).with_answer(1, positions)
+    }
+}
+
+/// Walks the guard, turning right at obstacles. Returns the number of distinct positions visited until the guard
+/// leaves the map, `None` if it repeats a state.
+fn walk(map: &[Vec<char>], start: (usize, usize)) -> Option<usize> {
+    const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+    let size = map.len() as isize;
+    let mut seen = vec![[false; 4]; map.len() * map.len()];
+    let (mut y, mut x, mut direction) = (start.0 as isize, start.1 as isize, 0);
+    let mut positions = 1;
+
+    loop {
+        let state = &mut seen[(y * size + x) as usize][direction];
+        if *state {
+            return None;
+        }
+        *state = true;
+
+        let (dy, dx) = DIRECTIONS[direction];
+        let (ny, nx) = (y + dy, x + dx);
+        if !(0..size).contains(&ny) || !(0..size).contains(&nx) {
+            return Some(positions);
+        }
+        if map[ny as usize][nx as usize] == '#' {
+            direction = (direction + 1) % 4;
+        } else {
+            (y, x) = (ny, nx);
+            if seen[(y * size + x) as usize] == [false; 4] {
+                positions += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Day 7: calibration equations. About half of the test values can be made with `+`, `*` and `||`.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(7),
+    generator: &Calibrations,
+};
+
+/// Test values are kept below this limit, so concatenating numbers in solutions does not overflow.
+const MAX_TEST_VALUE: u64 = 100_000_000_000_000;
+
+/// The size is the number of equations.
+struct Calibrations;
+
+impl InputGenerator for Calibrations {
+    fn default_size(&self) -> usize {
+        850
+    }
+
+    fn test_size(&self) -> usize {
+        50
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let equations: Vec<(u64, Vec<u64>)> = (0..size)
+            .map(|_| loop {
+                let len = rng.range(2..13) as usize;
+                let numbers: Vec<u64> = (0..len)
+                    .map(|_| {
+                        if rng.chance(0.8) {
+                            rng.range(1..100)
+                        } else {
+                            rng.range(100..1000)
+                        }
+                    })
+                    .collect();
+
+                let value = if rng.chance(0.5) {
+                    evaluate(rng, &numbers)
+                } else {
+                    let limit = evaluate(rng, &numbers).unwrap_or(MAX_TEST_VALUE);
+                    Some(rng.range(1..limit.max(2)))
+                };
+
+                if let Some(value) = value.filter(|x| *x < MAX_TEST_VALUE) {
+                    break (value, numbers);
+                }
+            })
+            .collect();
+
+        let input = equations
+            .iter()
+            .map(|(value, numbers)| {
+                let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+                format!("{value}: {}", numbers.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // random test values may be possible by chance, so every equation is checked.
+        let calibration = |concat: bool| -> u64 {
+            equations
+                .iter()
+                .filter(|(value, numbers)| is_possible(*value, numbers[0], &numbers[1..], concat))
+                .map(|(value, _)| value)
+                .sum()
+        };
+
+        GeneratedInput::new(input)
+            .with_answer(1, calibration(false))
+            .with_answer(2, calibration(true))
+    }
+}
+
+/// Whether `value` can be made from `acc` and the remaining numbers, optionally with the concatenation operator.
+fn is_possible(value: u64, acc: u64, numbers: &[u64], concat: bool) -> bool {
+    let Some((x, rest)) = numbers.split_first() else {
+        return acc == value;
+    };
+    // operators never decrease the value.
+    if acc > value {
+        return false;
+    }
+
+    is_possible(value, acc + x, rest, concat)
+        || is_possible(value, acc * x, rest, concat)
+        || concat && is_possible(value, acc * 10u64.pow(x.ilog10() + 1) + x, rest, concat)
+}
+
+/// Evaluates the numbers left to right with random operators, `None` if the value gets too large.
+fn evaluate(rng: &mut Rng, numbers: &[u64]) -> Option<u64> {
+    numbers[1..].iter().try_fold(numbers[0], |acc, x| {
+        let value = match rng.range(0..3) {
+            0 => acc.checked_add(*x),
+            1 => acc.checked_mul(*x),
+            _ => format!("{acc}{x}").parse().ok(),
+        };
+        value.filter(|x| *x < MAX_TEST_VALUE)
+    })
+}
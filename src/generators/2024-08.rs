@@ -0,0 +1,85 @@
+//! Day 8: a map of antennas. Every frequency has a handful of antennas.
+use std::collections::HashSet;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(8),
+    generator: &AntennaMap,
+};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The size is the side length of the map.
+struct AntennaMap;
+
+impl InputGenerator for AntennaMap {
+    fn default_size(&self) -> usize {
+        50
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let mut map = vec![vec!['.'; size]; size];
+
+        // 3 to 4 antennas per frequency, about one antenna per 14 cells.
+        let frequencies = (size * size / 48).clamp(1, FREQUENCIES.len());
+        for frequency in &FREQUENCIES[..frequencies] {
+            for _ in 0..rng.range(3..5) {
+                let (y, x) = (rng.index(size), rng.index(size));
+                if map[y][x] == '.' {
+                    map[y][x] = char::from(*frequency);
+                }
+            }
+        }
+
+        let input = map
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        GeneratedInput::new(input)
+            .with_answer(1, antinodes(&map, false))
+            .with_answer(2, antinodes(&map, true))
+    }
+}
+
+/// Counts the locations of antinodes, which continue in line with two antennas of a frequency with `harmonics`.
+fn antinodes(map: &[Vec<char>], harmonics: bool) -> usize {
+    let size = map.len() as isize;
+    let antennas: Vec<(isize, isize, char)> = map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, c)| **c != '.')
+                .map(move |(x, c)| (y as isize, x as isize, *c))
+        })
+        .collect();
+
+    let mut locations = HashSet::new();
+    for (ay, ax, a) in &antennas {
+        for (by, bx, b) in &antennas {
+            if a != b || (ay, ax) == (by, bx) {
+                continue;
+            }
+            let (dy, dx) = (by - ay, bx - ax);
+            let steps = if harmonics { 0..size } else { 1..2 };
+            for step in steps {
+                let (y, x) = (by + dy * step, bx + dx * step);
+                if !(0..size).contains(&y) || !(0..size).contains(&x) {
+                    break;
+                }
+                locations.insert((y, x));
+            }
+        }
+    }
+    locations.len()
+}
@@ -0,0 +1,106 @@
+//! Day 9: a disk map of alternating file and free space lengths, starting and ending with a file.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(9),
+    generator: &DiskMap,
+};
+
+/// The size is the number of digits, it is rounded to an odd number.
+struct DiskMap;
+
+impl InputGenerator for DiskMap {
+    fn default_size(&self) -> usize {
+        19999
+    }
+
+    fn test_size(&self) -> usize {
+        199
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let lengths: Vec<usize> = (0..size | 1)
+            .map(|i| {
+                // files take at least one block, free space may be empty.
+                if i % 2 == 0 {
+                    rng.index(9) + 1
+                } else {
+                    rng.index(10)
+                }
+            })
+            .collect();
+
+        let input = lengths
+            .iter()
+            .map(|len| char::from(b'0' + *len as u8))
+            .collect();
+
+        GeneratedInput::new(input)
+            .with_answer(1, compact_blocks(&lengths))
+            .with_answer(2, compact_files(&lengths))
+    }
+}
+
+/// Moves single blocks from the end of the disk to the leftmost free block and returns the checksum.
+fn compact_blocks(lengths: &[usize]) -> usize {
+    let mut blocks: Vec<Option<usize>> = lengths
+        .iter()
+        .enumerate()
+        .flat_map(|(i, len)| vec![(i % 2 == 0).then_some(i / 2); *len])
+        .collect();
+
+    let (mut free, mut last) = (0, blocks.len() - 1);
+    loop {
+        while blocks[free].is_some() {
+            free += 1;
+        }
+        while blocks[last].is_none() {
+            last -= 1;
+        }
+        if free >= last {
+            break;
+        }
+        blocks.swap(free, last);
+    }
+
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, id)| i * id.unwrap_or(0))
+        .sum()
+}
+
+/// Moves whole files to the leftmost span of free space that fits them, once per file, and returns the checksum.
+fn compact_files(lengths: &[usize]) -> usize {
+    // files and free spans as `(start, len)`, files are indexed by their id.
+    let (mut files, mut spans) = (vec![], vec![]);
+    let mut start = 0;
+    for (i, len) in lengths.iter().enumerate() {
+        if i % 2 == 0 {
+            files.push((start, *len));
+        } else {
+            spans.push((start, *len));
+        }
+        start += len;
+    }
+
+    for file in files.iter_mut().rev() {
+        if let Some(span) = spans
+            .iter_mut()
+            .take_while(|span| span.0 < file.0)
+            .find(|span| span.1 >= file.1)
+        {
+            file.0 = span.0;
+            span.0 += file.1;
+            span.1 -= file.1;
+        }
+    }
+
+    files
+        .iter()
+        .enumerate()
+        .map(|(id, (start, len))| (*start..start + len).sum::<usize>() * id)
+        .sum()
+}
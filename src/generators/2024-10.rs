@@ -0,0 +1,109 @@
+//! Day 10: a topographic map with hiking trails, paths that climb from height 0 to 9 one step at a time.
+use std::collections::HashSet;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(10),
+    generator: &TopographicMap,
+};
+
+/// The size is the side length of the map.
+struct TopographicMap;
+
+impl InputGenerator for TopographicMap {
+    fn default_size(&self) -> usize {
+        50
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let mut map: Vec<Vec<Option<u8>>> = vec![vec![None; size]; size];
+
+        // trails are carved as random walks. They may join a carved trail if the heights line up, which creates forks.
+        for _ in 0..size * size / 8 {
+            let (mut y, mut x) = (rng.index(size), rng.index(size));
+            if map[y][x].is_some() {
+                continue;
+            }
+            map[y][x] = Some(0);
+
+            for height in 1..10 {
+                let next: Vec<(usize, usize)> = [
+                    (y.wrapping_sub(1), x),
+                    (y + 1, x),
+                    (y, x.wrapping_sub(1)),
+                    (y, x + 1),
+                ]
+                .into_iter()
+                .filter(|(ny, nx)| {
+                    *ny < size && *nx < size && map[*ny][*nx].is_none_or(|h| h == height)
+                })
+                .collect();
+                if next.is_empty() {
+                    break;
+                }
+                (y, x) = *rng.choose(&next);
+                map[y][x] = Some(height);
+            }
+        }
+
+        // the rest of the map has random heights.
+        let map: Vec<Vec<u8>> = map
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|h| h.unwrap_or_else(|| rng.range(0..10) as u8))
+                    .collect()
+            })
+            .collect();
+
+        let input = map
+            .iter()
+            .map(|row| row.iter().map(|h| char::from(b'0' + h)).collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let trailheads: Vec<(usize, usize)> = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (y, x)))
+            .filter(|(y, x)| map[*y][*x] == 0)
+            .collect();
+
+        // the score counts the distinct ends of the trails of a trailhead, the rating counts the trails.
+        let (mut score, mut rating) = (0, 0);
+        for trailhead in trailheads {
+            let ends = trail_ends(&map, trailhead);
+            score += ends.iter().collect::<HashSet<_>>().len();
+            rating += ends.len();
+        }
+
+        GeneratedInput::new(input)
+            .with_answer(1, score)
+            .with_answer(2, rating)
+    }
+}
+
+/// Returns the end of every hiking trail that starts at `start`, ends are repeated for every trail that reaches them.
+fn trail_ends(map: &[Vec<u8>], start: (usize, usize)) -> Vec<(usize, usize)> {
+    let (y, x) = start;
+    let height = map[y][x];
+    if height == 9 {
+        return vec![start];
+    }
+
+    [
+        (y.wrapping_sub(1), x),
+        (y + 1, x),
+        (y, x.wrapping_sub(1)),
+        (y, x + 1),
+    ]
+    .into_iter()
+    .filter(|(ny, nx)| map.get(*ny).and_then(|row| row.get(*nx)) == Some(&(height + 1)))
+    .flat_map(|next| trail_ends(map, next))
+    .collect()
+}
@@ -0,0 +1,72 @@
+//! Day 11: a single line of engraved stones.
+use std::collections::HashMap;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(11),
+    generator: &Stones,
+};
+
+/// The size is the number of stones.
+struct Stones;
+
+impl InputGenerator for Stones {
+    fn default_size(&self) -> usize {
+        8
+    }
+
+    fn test_size(&self) -> usize {
+        8
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let stones: Vec<u64> = (0..size)
+            .map(|_| {
+                let digits = rng.range(1..8) as u32;
+                rng.range(0..10u64.pow(digits))
+            })
+            .collect();
+
+        let input = stones
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        GeneratedInput::new(input)
+            .with_answer(1, count_stones(&stones, 25))
+            .with_answer(2, count_stones(&stones, 75))
+    }
+}
+
+/// Counts the stones after blinking, stones with the same number are only changed once per blink.
+fn count_stones(stones: &[u64], blinks: usize) -> u64 {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for stone in stones {
+        *counts.entry(*stone).or_default() += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut next = HashMap::new();
+        for (stone, count) in counts {
+            let digits = if stone == 0 { 1 } else { stone.ilog10() + 1 };
+            let changed = if stone == 0 {
+                vec![1]
+            } else if digits % 2 == 0 {
+                let half = 10u64.pow(digits / 2);
+                vec![stone / half, stone % half]
+            } else {
+                vec![stone * 2024]
+            };
+            for stone in changed {
+                *next.entry(stone).or_default() += count;
+            }
+        }
+        counts = next;
+    }
+
+    counts.values().sum()
+}
@@ -0,0 +1,123 @@
+//! Day 12: a garden of plant regions, grown from random seeds. Neighbouring regions may share a plant.
+use std::collections::VecDeque;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(12),
+    generator: &GardenMap,
+};
+
+/// The size is the side length of the garden.
+struct GardenMap;
+
+impl InputGenerator for GardenMap {
+    fn default_size(&self) -> usize {
+        140
+    }
+
+    fn test_size(&self) -> usize {
+        30
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let mut map = vec![vec![None; size]; size];
+        let mut queue = VecDeque::new();
+
+        // about 30 plots per region, real inputs have a few hundred regions.
+        for _ in 0..(size * size / 30).max(1) {
+            let (y, x) = (rng.index(size), rng.index(size));
+            let plant = char::from(b'A' + rng.range(0..26) as u8);
+            map[y][x] = Some(plant);
+            queue.push_back((y, x));
+        }
+
+        // regions grow one plot at a time, picking a random plot of the queue keeps their borders ragged.
+        while !queue.is_empty() {
+            let index = rng.index(queue.len());
+            queue.swap(0, index);
+            let (y, x) = queue.pop_front().unwrap();
+
+            for (ny, nx) in [
+                (y.wrapping_sub(1), x),
+                (y + 1, x),
+                (y, x.wrapping_sub(1)),
+                (y, x + 1),
+            ] {
+                if ny < size && nx < size && map[ny][nx].is_none() {
+                    map[ny][nx] = map[y][x];
+                    queue.push_back((ny, nx));
+                }
+            }
+        }
+
+        let map: Vec<Vec<char>> = map
+            .iter()
+            .map(|row| row.iter().map(|plant| plant.unwrap()).collect())
+            .collect();
+
+        let input = map
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let (perimeter_price, sides_price) = prices(&map);
+        GeneratedInput::new(input)
+            .with_answer(1, perimeter_price)
+            .with_answer(2, sides_price)
+    }
+}
+
+/// Returns the total price of fencing by perimeter and by sides. A region has as many sides as corners.
+fn prices(map: &[Vec<char>]) -> (usize, usize) {
+    let size = map.len() as isize;
+    let plant = |y: isize, x: isize| {
+        ((0..size).contains(&y) && (0..size).contains(&x)).then(|| map[y as usize][x as usize])
+    };
+
+    let mut seen = vec![vec![false; map.len()]; map.len()];
+    let (mut perimeter_price, mut sides_price) = (0, 0);
+
+    for y in 0..size {
+        for x in 0..size {
+            if seen[y as usize][x as usize] {
+                continue;
+            }
+            seen[y as usize][x as usize] = true;
+
+            let region = plant(y, x);
+            let (mut area, mut perimeter, mut corners) = (0, 0, 0);
+            let mut stack = vec![(y, x)];
+
+            while let Some((y, x)) = stack.pop() {
+                area += 1;
+                for (dy, dx) in [(-1, 0), (0, 1), (1, 0), (0, -1)] {
+                    let (ny, nx) = (y + dy, x + dx);
+                    if plant(ny, nx) != region {
+                        perimeter += 1;
+                    } else if !seen[ny as usize][nx as usize] {
+                        seen[ny as usize][nx as usize] = true;
+                        stack.push((ny, nx));
+                    }
+
+                    // the next direction clockwise, together they point to a corner of the plot.
+                    let (cy, cx) = (dx, -dy);
+                    let side = plant(y + dy, x + dx) == region;
+                    let other = plant(y + cy, x + cx) == region;
+                    let diagonal = plant(y + dy + cy, x + dx + cx) == region;
+                    if !side && !other || side && other && !diagonal {
+                        corners += 1;
+                    }
+                }
+            }
+
+            perimeter_price += area * perimeter;
+            sides_price += area * corners;
+        }
+    }
+
+    (perimeter_price, sides_price)
+}
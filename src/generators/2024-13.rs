@@ -0,0 +1,130 @@
+//! Day 13: claw machines. Some prizes can be won with up to 100 presses per button,
+//! others only once their position is corrected by 10000000000000 in part two.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(13),
+    generator: &ClawMachines,
+};
+
+/// The size is the number of machines.
+struct ClawMachines;
+
+impl InputGenerator for ClawMachines {
+    fn default_size(&self) -> usize {
+        320
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let machines: Vec<Machine> = (0..size)
+            .map(|_| match rng.range(0..3) {
+                0 => near_machine(rng),
+                1 => far_machine(rng),
+                _ => {
+                    let (a, b) = buttons(rng);
+                    (a, b, (rng.range(1000..20000), rng.range(1000..20000)))
+                }
+            })
+            .collect();
+
+        let input = machines
+            .iter()
+            .map(|(a, b, prize)| {
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                    a.0, a.1, b.0, b.1, prize.0, prize.1
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        // random prizes may be winnable by chance, so every machine is solved.
+        let tokens = |correction: i64| -> i64 {
+            machines
+                .iter()
+                .filter_map(|machine| tokens(machine, correction))
+                .sum()
+        };
+
+        GeneratedInput::new(input)
+            .with_answer(1, tokens(0))
+            .with_answer(2, tokens(CORRECTION))
+    }
+}
+
+type Position = (u64, u64);
+
+/// Buttons A and B and the prize of a claw machine.
+type Machine = (Position, Position, Position);
+
+/// The correction of the prize positions in part two.
+const CORRECTION: i64 = 10000000000000;
+
+/// The tokens to win the prize of a machine, `None` if it can't be won. The buttons are never parallel,
+/// so there is a single way to win. Without a correction, buttons are pressed at most 100 times.
+fn tokens(machine: &Machine, correction: i64) -> Option<i64> {
+    let ((ax, ay), (bx, by), (x, y)) = *machine;
+    let (ax, ay, bx, by) = (ax as i64, ay as i64, bx as i64, by as i64);
+    let (x, y) = (x as i64 + correction, y as i64 + correction);
+
+    let det = ax * by - ay * bx;
+    let (a, b) = (x * by - y * bx, ax * y - ay * x);
+    if a % det != 0 || b % det != 0 {
+        return None;
+    }
+
+    let (a, b) = (a / det, b / det);
+    let limit = if correction == 0 { 100 } else { i64::MAX };
+    (a >= 0 && b >= 0 && a <= limit && b <= limit).then_some(3 * a + b)
+}
+
+/// Two buttons that don't move the claw in the same direction.
+fn buttons(rng: &mut Rng) -> (Position, Position) {
+    loop {
+        let a = (rng.range(10..100), rng.range(10..100));
+        let b = (rng.range(10..100), rng.range(10..100));
+        if a.0 * b.1 != a.1 * b.0 {
+            return (a, b);
+        }
+    }
+}
+
+/// A machine whose prize is won with up to 100 presses per button.
+fn near_machine(rng: &mut Rng) -> Machine {
+    let (a, b) = buttons(rng);
+    let (presses_a, presses_b) = (rng.range(1..101), rng.range(1..101));
+    let prize = (
+        presses_a * a.0 + presses_b * b.0,
+        presses_a * a.1 + presses_b * b.1,
+    );
+    (a, b, prize)
+}
+
+/// A machine whose prize can be won after the correction of part two.
+fn far_machine(rng: &mut Rng) -> Machine {
+    loop {
+        // one button moves further right and the other further up, so the corrected prize is between them.
+        let (a, b) = buttons(rng);
+        if (a.0 > a.1) == (b.0 > b.1) {
+            continue;
+        }
+
+        let (ax, ay, bx, by) = (a.0 as i64, a.1 as i64, b.0 as i64, b.1 as i64);
+        let det = ax * by - ay * bx;
+
+        // a random prize is winnable if it lies on the lattice spanned by the buttons, about one in `det` does.
+        for _ in 0..det.abs() * 4 {
+            let prize = (rng.range(1000..20000), rng.range(1000..20000));
+            let (x, y) = (prize.0 as i64 + CORRECTION, prize.1 as i64 + CORRECTION);
+            if (x * by - y * bx) % det == 0 && (ax * y - ay * x) % det == 0 {
+                return (a, b, prize);
+            }
+        }
+    }
+}
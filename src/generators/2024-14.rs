@@ -0,0 +1,157 @@
+//! Day 14: robots on a 101 by 103 tile area. At a random second, the robots arrange into a framed christmas tree.
+use std::collections::HashSet;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(14),
+    generator: &Robots,
+};
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+/// Width and height of the frame around the tree.
+const FRAME: (i64, i64) = (31, 33);
+
+/// The size is the number of robots. It is raised to fit the tree, which takes about half of the default size.
+struct Robots;
+
+impl InputGenerator for Robots {
+    fn default_size(&self) -> usize {
+        500
+    }
+
+    fn test_size(&self) -> usize {
+        300
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        // the tree is left of the vertical center and across the horizontal center.
+        let origin = (
+            rng.range(0..WIDTH as u64 / 2 - FRAME.0 as u64) as i64,
+            HEIGHT / 2 - 15,
+        );
+        let tree = tree(origin);
+        let occupied: HashSet<(i64, i64)> = tree.iter().copied().collect();
+
+        let mut positions = tree;
+        while positions.len() < size {
+            let position = (
+                rng.range(0..WIDTH as u64) as i64,
+                rng.range(0..HEIGHT as u64) as i64,
+            );
+            if !occupied.contains(&position) {
+                positions.push(position);
+            }
+        }
+        rng.shuffle(&mut positions);
+
+        // positions are those at the second of the tree, the robots are moved back to their start.
+        let second = rng.range(1..(WIDTH * HEIGHT) as u64) as i64;
+        let robots: Vec<Robot> = loop {
+            let robots: Vec<Robot> = positions
+                .iter()
+                .map(|(x, y)| {
+                    let velocity = (velocity(rng), velocity(rng));
+                    let start = (
+                        (x - velocity.0 * second).rem_euclid(WIDTH),
+                        (y - velocity.1 * second).rem_euclid(HEIGHT),
+                    );
+                    (start, velocity)
+                })
+                .collect();
+
+            // every 101 seconds, the robots of the tree return to its columns. They must not line up by chance then.
+            let is_first_line =
+                (1..=second / WIDTH).all(|n| longest_line(&robots, second - n * WIDTH) < MIN_LINE);
+            if is_first_line {
+                break robots;
+            }
+        };
+
+        let input = robots
+            .iter()
+            .map(|((x, y), (vx, vy))| format!("p={x},{y} v={vx},{vy}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        GeneratedInput::new(input)
+            .with_answer(1, safety_factor(&robots))
+            .with_answer(2, second)
+    }
+}
+
+/// The product of the robots in each quadrant after 100 seconds. Robots on the middle lines are not counted.
+fn safety_factor(robots: &[Robot]) -> usize {
+    let mut quadrants = [0; 4];
+    for ((x, y), (vx, vy)) in robots {
+        let x = (x + vx * 100).rem_euclid(WIDTH);
+        let y = (y + vy * 100).rem_euclid(HEIGHT);
+        if x != WIDTH / 2 && y != HEIGHT / 2 {
+            quadrants[usize::from(x > WIDTH / 2) * 2 + usize::from(y > HEIGHT / 2)] += 1;
+        }
+    }
+    quadrants.iter().product()
+}
+
+/// The start position and velocity of a robot.
+type Robot = ((i64, i64), (i64, i64));
+
+/// Robots in a row that are taken for part of the picture.
+const MIN_LINE: usize = 6;
+
+/// The longest horizontal line of tiles with a single robot each, after `second` seconds.
+fn longest_line(robots: &[Robot], second: i64) -> usize {
+    let mut counts = vec![0u8; (WIDTH * HEIGHT) as usize];
+    for ((x, y), (vx, vy)) in robots {
+        let x = (x + vx * second).rem_euclid(WIDTH);
+        let y = (y + vy * second).rem_euclid(HEIGHT);
+        counts[(y * WIDTH + x) as usize] += 1;
+    }
+
+    counts
+        .chunks(WIDTH as usize)
+        .flat_map(|row| row.split(|count| *count != 1))
+        .map(<[u8]>::len)
+        .max()
+        .unwrap_or(0)
+}
+
+fn velocity(rng: &mut Rng) -> i64 {
+    let speed = rng.range(1..100) as i64;
+    if rng.chance(0.5) {
+        speed
+    } else {
+        -speed
+    }
+}
+
+/// The tiles of a framed tree with its top left corner at `origin`.
+fn tree(origin: (i64, i64)) -> Vec<(i64, i64)> {
+    let (width, height) = FRAME;
+    let center = width / 2;
+    let mut tiles = vec![];
+
+    for y in 0..height {
+        for x in 0..width {
+            let is_frame = y == 0 || y == height - 1 || x == 0 || x == width - 1;
+            // the outlines of three stacked triangles, then the trunk.
+            let is_tree = match y - 2 {
+                row @ 0..=26 => {
+                    let half_width = row % 9 + row / 9 * 2;
+                    let offset = (x - center).abs();
+                    offset == half_width || row % 9 == 8 && offset <= half_width
+                }
+                27..=29 => (x - center).abs() <= 1,
+                _ => false,
+            };
+            if is_frame || is_tree {
+                tiles.push((origin.0 + x, origin.1 + y));
+            }
+        }
+    }
+    tiles
+}
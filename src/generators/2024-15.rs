@@ -0,0 +1,152 @@
+//! Day 15: a warehouse enclosed by walls with boxes and a robot, followed by the moves of the robot.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(15),
+    generator: &Warehouse,
+};
+
+/// Moves per line, as in the puzzle input.
+const MOVES_PER_LINE: usize = 1000;
+
+/// The size is the side length of the warehouse, there are 8 moves per tile.
+struct Warehouse;
+
+impl InputGenerator for Warehouse {
+    fn default_size(&self) -> usize {
+        50
+    }
+
+    fn test_size(&self) -> usize {
+        10
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let size = size.max(3);
+        let robot = (size / 2, size / 2);
+
+        let map: Vec<Vec<char>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        if y == 0 || x == 0 || y == size - 1 || x == size - 1 {
+                            '#'
+                        } else if (y, x) == robot {
+                            '@'
+                        } else if rng.chance(0.08) {
+                            '#'
+                        } else if rng.chance(0.3) {
+                            'O'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let moves: Vec<char> = (0..size * size * 8)
+            .map(|_| *rng.choose(&['^', 'v', '<', '>']))
+            .collect();
+        let lines: Vec<String> = moves
+            .chunks(MOVES_PER_LINE)
+            .map(|line| line.iter().collect())
+            .collect();
+        let rows: Vec<String> = map.iter().map(|row| row.iter().collect()).collect();
+
+        let input = format!("{}\n\n{}", rows.join("\n"), lines.join("\n"));
+        GeneratedInput::new(input)
+            .with_answer(1, gps_sum(&map, &moves, false))
+            .with_answer(2, gps_sum(&map, &moves, true))
+    }
+}
+
+/// Moves the robot and returns the sum of the GPS coordinates of the boxes.
+/// With `wide`, everything except the robot is twice as wide.
+fn gps_sum(map: &[Vec<char>], moves: &[char], wide: bool) -> usize {
+    let mut grid: Vec<Vec<char>> = map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .flat_map(|tile| match (wide, tile) {
+                    (false, _) => vec![*tile],
+                    (true, 'O') => vec!['[', ']'],
+                    (true, '@') => vec!['@', '.'],
+                    (true, _) => vec![*tile, *tile],
+                })
+                .collect()
+        })
+        .collect();
+
+    let (mut y, mut x) = grid
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| row.iter().position(|tile| *tile == '@').map(|x| (y, x)))
+        .unwrap();
+
+    for direction in moves {
+        let (dy, dx): (isize, isize) = match direction {
+            '^' => (-1, 0),
+            'v' => (1, 0),
+            '<' => (0, -1),
+            _ => (0, 1),
+        };
+
+        // the tiles that move, found outwards from the robot. Pushing a wide box up or down also pushes its other half.
+        let mut tiles = vec![(y, x)];
+        let mut index = 0;
+        let mut blocked = false;
+        while index < tiles.len() && !blocked {
+            let (ty, tx) = tiles[index];
+            index += 1;
+
+            let next = (ty.wrapping_add_signed(dy), tx.wrapping_add_signed(dx));
+            let mut push = |tile: (usize, usize)| {
+                if !tiles.contains(&tile) {
+                    tiles.push(tile);
+                }
+            };
+            match grid[next.0][next.1] {
+                '#' => blocked = true,
+                'O' => push(next),
+                '[' => {
+                    push(next);
+                    if dy != 0 {
+                        push((next.0, next.1 + 1));
+                    }
+                }
+                ']' => {
+                    push(next);
+                    if dy != 0 {
+                        push((next.0, next.1 - 1));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if blocked {
+            continue;
+        }
+
+        // the farthest tiles move first, so no tile is overwritten before it moved.
+        for (ty, tx) in tiles.into_iter().rev() {
+            let (ny, nx) = (ty.wrapping_add_signed(dy), tx.wrapping_add_signed(dx));
+            grid[ny][nx] = grid[ty][tx];
+            grid[ty][tx] = '.';
+        }
+        (y, x) = (y.wrapping_add_signed(dy), x.wrapping_add_signed(dx));
+    }
+
+    grid.iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, tile)| **tile == 'O' || **tile == '[')
+                .map(move |(x, _)| 100 * y + x)
+        })
+        .sum()
+}
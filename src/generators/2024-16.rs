@@ -0,0 +1,147 @@
+//! Day 16: a maze with loops, starting in the bottom left and ending in the top right corner.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(16),
+    generator: &ReindeerMaze,
+};
+
+/// The size is the side length of the maze, it is rounded to an odd number.
+struct ReindeerMaze;
+
+impl InputGenerator for ReindeerMaze {
+    fn default_size(&self) -> usize {
+        141
+    }
+
+    fn test_size(&self) -> usize {
+        21
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let size = size.max(5) | 1;
+        let mut maze = maze(rng, size);
+
+        // removing walls between corridors adds loops, so there are several best paths.
+        for _ in 0..size * size / 20 {
+            let (y, x) = (rng.range(1..size as u64 - 1), rng.range(1..size as u64 - 1));
+            let (y, x) = (y as usize, x as usize);
+            if (y + x) % 2 == 1 {
+                maze[y][x] = '.';
+            }
+        }
+
+        maze[size - 2][1] = 'S';
+        maze[1][size - 2] = 'E';
+
+        let input = maze
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        // a tile is on a best path if the best scores from the start and to the end add up to the lowest score.
+        let from_start = scores(&maze, &[(size - 2, 1, EAST)], false);
+        let end_states: Vec<State> = (0..4).map(|d| (1, size - 2, d)).collect();
+        let to_end = scores(&maze, &end_states, true);
+
+        let lowest = (0..4)
+            .map(|d| from_start[index(size, (1, size - 2, d))])
+            .min()
+            .unwrap();
+        let tiles = (0..size * size)
+            .filter(|tile| {
+                (0..4).any(|d| {
+                    let state = tile * 4 + d;
+                    from_start[state].saturating_add(to_end[state]) == lowest
+                })
+            })
+            .count();
+
+        GeneratedInput::new(input)
+            .with_answer(1, lowest)
+            .with_answer(2, tiles)
+    }
+}
+
+/// A position and the direction the reindeer faces, as an index of `DIRECTIONS`.
+type State = (usize, usize, usize);
+
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const EAST: usize = 1;
+
+fn index(size: usize, (y, x, d): State) -> usize {
+    (y * size + x) * 4 + d
+}
+
+/// The lowest score of every state, starting at any of `starts`. With `reverse`, the reindeer moves backwards,
+/// which gives the lowest score from every state to the starts.
+fn scores(maze: &[Vec<char>], starts: &[State], reverse: bool) -> Vec<usize> {
+    let size = maze.len();
+    let mut scores = vec![usize::MAX; size * size * 4];
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        scores[index(size, *start)] = 0;
+        queue.push(Reverse((0, *start)));
+    }
+
+    while let Some(Reverse((score, (y, x, d)))) = queue.pop() {
+        if score > scores[index(size, (y, x, d))] {
+            continue;
+        }
+
+        let (dy, dx) = DIRECTIONS[d];
+        let (dy, dx) = if reverse { (-dy, -dx) } else { (dy, dx) };
+        let (ny, nx) = (y.wrapping_add_signed(dy), x.wrapping_add_signed(dx));
+        let mut next = vec![
+            ((y, x, (d + 1) % 4), score + 1000),
+            ((y, x, (d + 3) % 4), score + 1000),
+        ];
+        if maze[ny][nx] != '#' {
+            next.push(((ny, nx, d), score + 1));
+        }
+
+        for (state, score) in next {
+            if score < scores[index(size, state)] {
+                scores[index(size, state)] = score;
+                queue.push(Reverse((score, state)));
+            }
+        }
+    }
+    scores
+}
+
+/// Carves a perfect maze with a randomized depth-first search. Corridors are on odd rows and columns.
+pub(crate) fn maze(rng: &mut Rng, size: usize) -> Vec<Vec<char>> {
+    let mut maze = vec![vec!['#'; size]; size];
+    let mut stack: Vec<(usize, usize)> = vec![(1, 1)];
+    maze[1][1] = '.';
+
+    while let Some(&(y, x)) = stack.last() {
+        let next: Vec<(usize, usize)> = [
+            (y.wrapping_sub(2), x),
+            (y + 2, x),
+            (y, x.wrapping_sub(2)),
+            (y, x + 2),
+        ]
+        .into_iter()
+        .filter(|(ny, nx)| *ny < size - 1 && *nx < size - 1 && maze[*ny][*nx] == '#')
+        .collect();
+
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (ny, nx) = *rng.choose(&next);
+        maze[(y + ny) / 2][(x + nx) / 2] = '.';
+        maze[ny][nx] = '.';
+        stack.push((ny, nx));
+    }
+    maze
+}
@@ -0,0 +1,112 @@
+//! Day 17: a 3-bit computer with a program that shifts register A by 3 bits per loop and outputs one value each time.
+//! Programs are only used if a value of register A that makes them output themselves can be found digit by digit.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(17),
+    generator: &ChronospatialComputer,
+};
+
+/// The size is the number of octal digits of register A, which is the number of values the program outputs.
+struct ChronospatialComputer;
+
+impl InputGenerator for ChronospatialComputer {
+    fn default_size(&self) -> usize {
+        16
+    }
+
+    fn test_size(&self) -> usize {
+        16
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let digits = size.clamp(1, 21) as u32;
+        let (program, quine) = loop {
+            let program = program(rng);
+            if let Some(quine) = self_output(&program) {
+                break (program, quine);
+            }
+        };
+
+        let register_a = rng.range(8u64.pow(digits - 1)..8u64.pow(digits));
+        let join = |values: &[u64]| {
+            values
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        let input = format!(
+            "Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+            join(&program)
+        );
+        GeneratedInput::new(input)
+            .with_answer(1, join(&run(&program, register_a)))
+            .with_answer(2, quine)
+    }
+}
+
+/// A program in the shape of the puzzle inputs: B is derived from the lowest 3 bits of A and the bits selected by B,
+/// then output, while A is shifted by 3 bits until it is zero.
+fn program(rng: &mut Rng) -> Vec<u64> {
+    let mut instructions = vec![
+        (2, 4),
+        (1, rng.range(0..8)),
+        (7, 5),
+        (1, rng.range(0..8)),
+        (4, rng.range(0..8)),
+        (5, 5),
+    ];
+    if rng.chance(0.5) {
+        instructions.swap(3, 4);
+    }
+    instructions.insert(rng.range(3..7) as usize, (0, 3));
+    instructions.push((3, 0));
+
+    instructions
+        .into_iter()
+        .flat_map(|(op, x)| [op, x])
+        .collect()
+}
+
+/// Searches the lowest value of register A that makes the program output itself, digit by digit from the last output.
+/// Every digit is the smallest that matches, searches that have to backtrack are not supported by all solutions.
+fn self_output(program: &[u64]) -> Option<u64> {
+    (1..=program.len()).rev().try_fold(0, |a, matched| {
+        (0..8)
+            .map(|digit| a * 8 + digit)
+            .find(|candidate| *candidate != 0 && run(program, *candidate) == program[matched - 1..])
+    })
+}
+
+fn run(program: &[u64], a: u64) -> Vec<u64> {
+    let (mut a, mut b, mut c) = (a, 0, 0);
+    let mut output = vec![];
+    let mut pointer = 0;
+
+    while pointer + 1 < program.len() {
+        let (op, literal) = (program[pointer], program[pointer + 1]);
+        let combo = match literal {
+            4 => a,
+            5 => b,
+            6 => c,
+            x => x,
+        };
+        pointer += 2;
+        match op {
+            0 => a >>= combo,
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => pointer = literal as usize,
+            4 => b ^= c,
+            5 => output.push(combo % 8),
+            6 => b = a >> combo,
+            7 => c = a >> combo,
+            _ => {}
+        }
+    }
+    output
+}
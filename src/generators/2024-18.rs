@@ -0,0 +1,90 @@
+//! Day 18: bytes falling into a 71 by 71 memory space, until they cut off the exit.
+//! The path to the exit is still open after the first kilobyte.
+use std::collections::VecDeque;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(18),
+    generator: &FallingBytes,
+};
+
+const SIZE: usize = 71;
+
+/// Bytes that have fallen when the shortest path is searched in part one.
+const KILOBYTE: usize = 1024;
+
+/// The size is the number of bytes. It is raised until the last byte cuts off the exit.
+struct FallingBytes;
+
+impl InputGenerator for FallingBytes {
+    fn default_size(&self) -> usize {
+        3450
+    }
+
+    fn test_size(&self) -> usize {
+        3450
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        loop {
+            let mut bytes: Vec<(usize, usize)> = (0..SIZE * SIZE)
+                .map(|i| (i % SIZE, i / SIZE))
+                .filter(|x| *x != (0, 0) && *x != (SIZE - 1, SIZE - 1))
+                .collect();
+            rng.shuffle(&mut bytes);
+
+            let Some(steps) = shortest_path(&bytes[..KILOBYTE]) else {
+                continue;
+            };
+
+            // the number of fallen bytes that cuts off the exit, the path only gets blocked by more bytes.
+            let counts: Vec<usize> = (KILOBYTE..=bytes.len()).collect();
+            let blocking =
+                counts[counts.partition_point(|n| shortest_path(&bytes[..*n]).is_some())];
+            let (x, y) = bytes[blocking - 1];
+
+            bytes.truncate(size.max(blocking));
+            let input = bytes
+                .iter()
+                .map(|(x, y)| format!("{x},{y}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            return GeneratedInput::new(input)
+                .with_answer(1, steps)
+                .with_answer(2, format!("{x},{y}"));
+        }
+    }
+}
+
+/// The steps of the shortest path from the top left corner to the exit after the bytes have fallen,
+/// `None` if the exit can't be reached.
+fn shortest_path(bytes: &[(usize, usize)]) -> Option<usize> {
+    let mut blocked = vec![vec![false; SIZE]; SIZE];
+    for (x, y) in bytes {
+        blocked[*y][*x] = true;
+    }
+
+    let mut queue = VecDeque::from([(0, 0, 0)]);
+    blocked[0][0] = true;
+    while let Some((x, y, steps)) = queue.pop_front() {
+        if (x, y) == (SIZE - 1, SIZE - 1) {
+            return Some(steps);
+        }
+        for (nx, ny) in [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ] {
+            if nx < SIZE && ny < SIZE && !blocked[ny][nx] {
+                blocked[ny][nx] = true;
+                queue.push_back((nx, ny, steps + 1));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,95 @@
+//! Day 19: towel patterns of striped colors and the designs to arrange from them.
+//! One color has no single-stripe towel, so some designs are impossible.
+use std::collections::HashSet;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(19),
+    generator: &Towels,
+};
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// Number of towel patterns, as in the puzzle input.
+const PATTERNS: usize = 447;
+
+/// The size is the number of designs.
+struct Towels;
+
+impl InputGenerator for Towels {
+    fn default_size(&self) -> usize {
+        400
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let missing = *rng.choose(&COLORS);
+        let stripes = |rng: &mut Rng, len: u64| -> String {
+            (0..rng.range(1..len))
+                .map(|_| *rng.choose(&COLORS))
+                .collect()
+        };
+
+        let mut patterns = HashSet::new();
+        while patterns.len() < PATTERNS {
+            let pattern = stripes(rng, 9);
+            if pattern != missing.to_string() {
+                patterns.insert(pattern);
+            }
+        }
+        let mut patterns: Vec<String> = patterns.into_iter().collect();
+        patterns.sort();
+        rng.shuffle(&mut patterns);
+
+        let designs: Vec<String> = (0..size)
+            .map(|_| {
+                let len = rng.range(20..61) as usize;
+                let mut design = String::new();
+                if rng.chance(0.6) {
+                    while design.len() < len {
+                        design.push_str(rng.choose(&patterns).as_str());
+                    }
+                } else {
+                    while design.len() < len {
+                        design.push(*rng.choose(&COLORS));
+                    }
+                }
+                design
+            })
+            .collect();
+
+        let ways: Vec<u128> = designs
+            .iter()
+            .map(|design| arrangements(design, &patterns))
+            .collect();
+
+        let input = format!("{}\n\n{}", patterns.join(", "), designs.join("\n"));
+        GeneratedInput::new(input)
+            .with_answer(1, ways.iter().filter(|ways| **ways > 0).count())
+            .with_answer(2, ways.iter().sum::<u128>())
+    }
+}
+
+/// Counts the different ways to arrange a design from the patterns.
+fn arrangements(design: &str, patterns: &[String]) -> u128 {
+    // the ways to arrange the first `i` stripes of the design.
+    let mut ways = vec![0; design.len() + 1];
+    ways[0] = 1;
+    for i in 0..design.len() {
+        if ways[i] == 0 {
+            continue;
+        }
+        for pattern in patterns {
+            if design[i..].starts_with(pattern.as_str()) {
+                ways[i + pattern.len()] += ways[i];
+            }
+        }
+    }
+    ways[design.len()]
+}
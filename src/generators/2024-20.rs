@@ -0,0 +1,93 @@
+//! Day 20: a racetrack, a single path from start to end through a grid of walls.
+use std::collections::VecDeque;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+use super::y2024_day16::maze;
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(20),
+    generator: &Racetrack,
+};
+
+/// The size is the side length of the grid, it is rounded to an odd number.
+struct Racetrack;
+
+impl InputGenerator for Racetrack {
+    fn default_size(&self) -> usize {
+        141
+    }
+
+    fn test_size(&self) -> usize {
+        41
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let size = size.max(5) | 1;
+        let maze = maze(rng, size);
+
+        // a perfect maze has a single path between two cells, the track is its longest one.
+        let (start, _) = farthest(&maze, (1, 1));
+        let (end, previous) = farthest(&maze, start);
+
+        let mut track = vec![vec!['#'; size]; size];
+        let mut path = vec![end];
+        while path[path.len() - 1] != start {
+            let (y, x) = path[path.len() - 1];
+            track[y][x] = '.';
+            path.push(previous[y][x]);
+        }
+        track[start.0][start.1] = 'S';
+        track[end.0][end.1] = 'E';
+
+        let input = track
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        GeneratedInput::new(input)
+            .with_answer(1, cheats(&path, 2))
+            .with_answer(2, cheats(&path, 20))
+    }
+}
+
+/// Counts the cheats of up to `duration` picoseconds that save at least 100 picoseconds.
+/// A cheat leads from one cell of the path to a later one, in as many steps as their distance.
+fn cheats(path: &[Cell], duration: usize) -> usize {
+    let mut count = 0;
+    for (i, (ay, ax)) in path.iter().enumerate() {
+        for (j, (by, bx)) in path.iter().enumerate().skip(i + 1) {
+            let distance = ay.abs_diff(*by) + ax.abs_diff(*bx);
+            if distance <= duration && j - i >= distance + 100 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// A cell of the maze, as `(y, x)`.
+type Cell = (usize, usize);
+
+/// Returns the cell farthest away from `from`, with the previous cell of every reached cell.
+fn farthest(maze: &[Vec<char>], from: Cell) -> (Cell, Vec<Vec<Cell>>) {
+    let size = maze.len();
+    let mut previous = vec![vec![(usize::MAX, usize::MAX); size]; size];
+    previous[from.0][from.1] = from;
+
+    let mut queue = VecDeque::from([from]);
+    let mut last = from;
+    while let Some((y, x)) = queue.pop_front() {
+        last = (y, x);
+        for (ny, nx) in [(y - 1, x), (y + 1, x), (y, x - 1), (y, x + 1)] {
+            if maze[ny][nx] != '#' && previous[ny][nx].0 == usize::MAX {
+                previous[ny][nx] = (y, x);
+                queue.push_back((ny, nx));
+            }
+        }
+    }
+    (last, previous)
+}
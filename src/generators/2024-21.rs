@@ -0,0 +1,114 @@
+//! Day 21: door codes of three digits, followed by `A`.
+use std::collections::HashMap;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(21),
+    generator: &DoorCodes,
+};
+
+/// The size is the number of codes.
+struct DoorCodes;
+
+impl InputGenerator for DoorCodes {
+    fn default_size(&self) -> usize {
+        5
+    }
+
+    fn test_size(&self) -> usize {
+        5
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let codes: Vec<String> = (0..size)
+            .map(|_| format!("{:03}A", rng.range(1..1000)))
+            .collect();
+
+        // the person uses a directional keypad as well, so there is one more than the keypads used by robots.
+        let complexity = |keypads: usize| -> u64 {
+            let mut cache = HashMap::new();
+            codes
+                .iter()
+                .map(|code| {
+                    let number: u64 = code[..3].parse().unwrap();
+                    number * presses(code, NUMERIC, keypads, &mut cache)
+                })
+                .sum()
+        };
+
+        GeneratedInput::new(codes.join("\n"))
+            .with_answer(1, complexity(3))
+            .with_answer(2, complexity(26))
+    }
+}
+
+/// Rows of the keypads, a space is the gap that robot arms must not point at.
+const NUMERIC: &[&str] = &["789", "456", "123", " 0A"];
+const DIRECTIONAL: &[&str] = &[" ^A", "<v>"];
+
+/// The fewest button presses of the person to type `keys` on a keypad, through a chain of `keypads` directional
+/// keypads. The person presses the last of them, the others are pressed by robots that start pointing at `A`.
+fn presses(
+    keys: &str,
+    keypad: &[&str],
+    keypads: usize,
+    cache: &mut HashMap<(char, char, usize), u64>,
+) -> u64 {
+    let mut from = 'A';
+    let mut total = 0;
+    for to in keys.chars() {
+        total += move_presses(from, to, keypad, keypads, cache);
+        from = to;
+    }
+    total
+}
+
+/// The fewest presses to move from one key to another and press it. Moves go either horizontally or vertically
+/// first, mixing the directions only takes more presses.
+fn move_presses(
+    from: char,
+    to: char,
+    keypad: &[&str],
+    keypads: usize,
+    cache: &mut HashMap<(char, char, usize), u64>,
+) -> u64 {
+    if keypads == 0 {
+        return 1;
+    }
+    // NOTE: both keypads have an `A` key, so only the directional keypad is cached.
+    let is_directional = keypad == DIRECTIONAL;
+    if let Some(presses) = cache.get(&(from, to, keypads)).filter(|_| is_directional) {
+        return *presses;
+    }
+
+    let position = |key: char| {
+        keypad
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.find(key).map(|x| (y, x)))
+            .unwrap()
+    };
+    let ((fy, fx), (ty, tx), gap) = (position(from), position(to), position(' '));
+
+    let vertical = if ty < fy { "^" } else { "v" }.repeat(fy.abs_diff(ty));
+    let horizontal = if tx < fx { "<" } else { ">" }.repeat(fx.abs_diff(tx));
+
+    let mut best = u64::MAX;
+    // the arm must not pass the gap at the corner of the move.
+    if (fy, tx) != gap {
+        let keys = format!("{horizontal}{vertical}A");
+        best = best.min(presses(&keys, DIRECTIONAL, keypads - 1, cache));
+    }
+    if (ty, fx) != gap {
+        let keys = format!("{vertical}{horizontal}A");
+        best = best.min(presses(&keys, DIRECTIONAL, keypads - 1, cache));
+    }
+
+    if is_directional {
+        cache.insert((from, to, keypads), best);
+    }
+    best
+}
@@ -0,0 +1,63 @@
+//! Day 22: the initial secret numbers of the buyers.
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(22),
+    generator: &Buyers,
+};
+
+/// The size is the number of buyers.
+struct Buyers;
+
+impl InputGenerator for Buyers {
+    fn default_size(&self) -> usize {
+        2000
+    }
+
+    fn test_size(&self) -> usize {
+        20
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let secrets: Vec<u64> = (0..size).map(|_| rng.range(1..1 << 24)).collect();
+
+        let input = secrets
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // bananas per sequence of four price changes, each buyer sells at the first occurrence of a sequence.
+        let mut bananas = vec![0; 19usize.pow(4)];
+        let mut last_secrets = 0;
+        for secret in &secrets {
+            let mut seen = vec![false; bananas.len()];
+            let (mut secret, mut sequence) = (*secret, 0);
+            for i in 0..2000 {
+                let next = evolve(secret);
+                let change = (next % 10 + 9 - secret % 10) as usize;
+                sequence = (sequence * 19 + change) % bananas.len();
+                if i >= 3 && !seen[sequence] {
+                    seen[sequence] = true;
+                    bananas[sequence] += next % 10;
+                }
+                secret = next;
+            }
+            last_secrets += secret;
+        }
+
+        GeneratedInput::new(input)
+            .with_answer(1, last_secrets)
+            .with_answer(2, bananas.iter().max().unwrap())
+    }
+}
+
+/// The next secret number of a buyer.
+fn evolve(secret: u64) -> u64 {
+    const PRUNE: u64 = 1 << 24;
+    let secret = (secret ^ (secret * 64)) % PRUNE;
+    let secret = (secret ^ (secret / 32)) % PRUNE;
+    (secret ^ (secret * 2048)) % PRUNE
+}
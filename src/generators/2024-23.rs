@@ -0,0 +1,93 @@
+//! Day 23: a map of the connections in a LAN. Every computer has 13 connections and one group of 13 computers
+//! is fully connected, the LAN party.
+use std::collections::HashSet;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(23),
+    generator: &Network,
+};
+
+/// Connections per computer, which is also the size of the LAN party.
+const CONNECTIONS: usize = 13;
+
+/// The size is the number of computers, at most 676 as names have two letters.
+struct Network;
+
+impl InputGenerator for Network {
+    fn default_size(&self) -> usize {
+        520
+    }
+
+    fn test_size(&self) -> usize {
+        40
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let mut names: Vec<String> = (b'a'..=b'z')
+            .flat_map(|a| (b'a'..=b'z').map(move |b| String::from_utf8(vec![a, b]).unwrap()))
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(size.clamp(CONNECTIONS + 1, names.len()));
+
+        let mut edges = HashSet::new();
+        let mut degrees = vec![0; names.len()];
+
+        // the party is fully connected, every member has one connection left to the rest of the LAN.
+        for a in 0..CONNECTIONS {
+            for b in a + 1..CONNECTIONS {
+                connect(&mut edges, &mut degrees, a, b);
+            }
+        }
+
+        // NOTE: a few computers may end up with less connections if no partner is left.
+        for _ in 0..names.len() * CONNECTIONS * 4 {
+            let (a, b) = (rng.index(names.len()), rng.index(names.len()));
+            if a != b && degrees[a] < CONNECTIONS && degrees[b] < CONNECTIONS {
+                connect(&mut edges, &mut degrees, a, b);
+            }
+        }
+
+        // triangles are counted once, from their smallest computer.
+        let triangles = edges
+            .iter()
+            .flat_map(|(a, b)| (b + 1..names.len()).map(move |c| (*a, *b, c)))
+            .filter(|(a, b, c)| edges.contains(&(*a, *c)) && edges.contains(&(*b, *c)))
+            .filter(|(a, b, c)| [a, b, c].iter().any(|x| names[**x].starts_with('t')))
+            .count();
+
+        // the party are the first computers, random connections are too few to form another group of its size.
+        let mut party: Vec<&str> = names[..CONNECTIONS].iter().map(String::as_str).collect();
+        party.sort_unstable();
+        let password = party.join(",");
+
+        let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+        edges.sort_unstable();
+        rng.shuffle(&mut edges);
+        let input = edges
+            .iter()
+            .map(|(a, b)| {
+                if rng.chance(0.5) {
+                    format!("{}-{}", names[*a], names[*b])
+                } else {
+                    format!("{}-{}", names[*b], names[*a])
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        GeneratedInput::new(input)
+            .with_answer(1, triangles)
+            .with_answer(2, password)
+    }
+}
+
+fn connect(edges: &mut HashSet<(usize, usize)>, degrees: &mut [usize], a: usize, b: usize) {
+    if edges.insert((a.min(b), a.max(b))) {
+        degrees[a] += 1;
+        degrees[b] += 1;
+    }
+}
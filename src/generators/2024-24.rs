@@ -0,0 +1,162 @@
+//! Day 24: the wires and gates of a ripple-carry adder, with the outputs of four pairs of gates swapped.
+use std::collections::{HashMap, HashSet};
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(24),
+    generator: &CrossedWires,
+};
+
+const SWAPPED_PAIRS: usize = 4;
+
+/// The size is the number of bits per input number. Solutions of part two may expect 45 bits.
+struct CrossedWires;
+
+struct Gate {
+    inputs: [String; 2],
+    op: &'static str,
+    output: String,
+}
+
+impl InputGenerator for CrossedWires {
+    fn default_size(&self) -> usize {
+        45
+    }
+
+    fn test_size(&self) -> usize {
+        45
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let bits = size.clamp(2, 63);
+        let mut used = HashSet::new();
+        let mut name = |rng: &mut Rng| loop {
+            let name: String = (0..3)
+                .map(|i| char::from(b'a' + rng.range(0..if i == 0 { 23 } else { 26 }) as u8))
+                .collect();
+            if used.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        let wire = |prefix: char, bit: usize| format!("{prefix}{bit:02}");
+        let mut gates = vec![];
+        let mut gate = |a: String, op, b: String, output: String| {
+            gates.push(Gate {
+                inputs: [a, b],
+                op,
+                output,
+            });
+            gates.len() - 1
+        };
+
+        // indexes of the gates of every bit that can be swapped.
+        let mut adders = vec![];
+
+        gate(wire('x', 0), "XOR", wire('y', 0), wire('z', 0));
+        let mut carry = name(rng);
+        gate(wire('x', 0), "AND", wire('y', 0), carry.clone());
+
+        for bit in 1..bits {
+            let (half_sum, half_carry, sum_carry) = (name(rng), name(rng), name(rng));
+            let next_carry = if bit == bits - 1 {
+                wire('z', bits)
+            } else {
+                name(rng)
+            };
+
+            let half_sum_gate = gate(wire('x', bit), "XOR", wire('y', bit), half_sum.clone());
+            let half_carry_gate = gate(wire('x', bit), "AND", wire('y', bit), half_carry.clone());
+            let sum_gate = gate(half_sum.clone(), "XOR", carry.clone(), wire('z', bit));
+            let sum_carry_gate = gate(half_sum, "AND", carry, sum_carry.clone());
+            let carry_gate = gate(half_carry, "OR", sum_carry, next_carry.clone());
+
+            adders.push([
+                half_sum_gate,
+                half_carry_gate,
+                sum_gate,
+                sum_carry_gate,
+                carry_gate,
+            ]);
+            carry = next_carry;
+        }
+
+        // swaps are in different bits away from both ends, and each is one of the kinds found in puzzle inputs.
+        let mut candidates: Vec<&[usize; 5]> =
+            adders.iter().skip(1).take(bits.saturating_sub(4)).collect();
+        rng.shuffle(&mut candidates);
+        let mut swapped = vec![];
+        for [half_sum, half_carry, sum, sum_carry, carry] in
+            candidates.into_iter().take(SWAPPED_PAIRS)
+        {
+            let (a, b) = *rng.choose(&[
+                (*sum, *carry),
+                (*sum, *sum_carry),
+                (*sum, *half_carry),
+                (*half_sum, *half_carry),
+            ]);
+            let output = gates[a].output.clone();
+            gates[a].output = std::mem::replace(&mut gates[b].output, output);
+            swapped.extend([gates[a].output.clone(), gates[b].output.clone()]);
+        }
+        swapped.sort();
+
+        let mut values = vec![];
+        for prefix in ['x', 'y'] {
+            for bit in 0..bits {
+                values.push((wire(prefix, bit), rng.range(0..2) == 1));
+            }
+        }
+        let z = simulate(&gates, &values);
+        let values: Vec<String> = values
+            .iter()
+            .map(|(wire, value)| format!("{wire}: {}", u8::from(*value)))
+            .collect();
+
+        rng.shuffle(&mut gates);
+        let gates: Vec<String> = gates
+            .iter_mut()
+            .map(|gate| {
+                rng.shuffle(&mut gate.inputs);
+                format!(
+                    "{} {} {} -> {}",
+                    gate.inputs[0], gate.op, gate.inputs[1], gate.output
+                )
+            })
+            .collect();
+
+        let input = format!("{}\n\n{}", values.join("\n"), gates.join("\n"));
+        GeneratedInput::new(input)
+            .with_answer(1, z)
+            .with_answer(2, swapped.join(","))
+    }
+}
+
+/// Evaluates the gates for the input values and returns the number on the `z` wires.
+fn simulate(gates: &[Gate], values: &[(String, bool)]) -> u64 {
+    let mut wires: HashMap<&str, bool> = values.iter().map(|(w, v)| (w.as_str(), *v)).collect();
+
+    // NOTE: swapped outputs stay within the gates of a bit, so the wiring has no cycles.
+    while wires.len() < values.len() + gates.len() {
+        for gate in gates {
+            let [a, b] = &gate.inputs;
+            if let (Some(a), Some(b)) = (wires.get(a.as_str()), wires.get(b.as_str())) {
+                let value = match gate.op {
+                    "AND" => a & b,
+                    "OR" => a | b,
+                    _ => a ^ b,
+                };
+                wires.insert(&gate.output, value);
+            }
+        }
+    }
+
+    wires
+        .iter()
+        .filter(|(wire, value)| wire.starts_with('z') && **value)
+        .map(|(wire, _)| 1 << wire[1..].parse::<u64>().unwrap())
+        .sum()
+}
@@ -0,0 +1,69 @@
+//! Day 25: schematics of locks and keys, five columns of pins in seven rows.
+use std::collections::HashSet;
+
+use crate::template::generator::{GeneratedInput, Generator, InputGenerator, Rng};
+use crate::{day, year};
+
+pub const GENERATOR: Generator = Generator {
+    year: year!(2024),
+    day: day!(25),
+    generator: &Schematics,
+};
+
+const COLUMNS: usize = 5;
+const ROWS: usize = 7;
+
+/// The size is the number of schematics, half of them are locks. There are no duplicate schematics.
+struct Schematics;
+
+impl InputGenerator for Schematics {
+    fn default_size(&self) -> usize {
+        500
+    }
+
+    fn test_size(&self) -> usize {
+        50
+    }
+
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput {
+        let mut seen = HashSet::new();
+        let mut schematics = vec![];
+        let (mut locks, mut keys) = (vec![], vec![]);
+
+        while schematics.len() < size {
+            let is_lock = schematics.len() % 2 == 0;
+            let heights: Vec<usize> = (0..COLUMNS).map(|_| rng.index(ROWS - 1)).collect();
+            if !seen.insert((is_lock, heights.clone())) {
+                continue;
+            }
+            if is_lock {
+                locks.push(heights.clone());
+            } else {
+                keys.push(heights.clone());
+            }
+
+            let rows: Vec<String> = (0..ROWS)
+                .map(|row| {
+                    // locks are filled from the top, keys from the bottom.
+                    let row = if is_lock { row } else { ROWS - 1 - row };
+                    heights
+                        .iter()
+                        .map(|height| if row <= *height { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            schematics.push(rows.join("\n"));
+        }
+
+        rng.shuffle(&mut schematics);
+
+        // a key fits a lock if their pins don't overlap in any column.
+        let fitting = locks
+            .iter()
+            .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+            .filter(|(lock, key)| lock.iter().zip(key.iter()).all(|(l, k)| l + k < ROWS - 1))
+            .count();
+
+        GeneratedInput::new(schematics.join("\n\n")).with_answer(1, fitting)
+    }
+}
@@ -1,3 +1,8 @@
+// Allows the solutions included in `solutions` to refer to the library by name.
+extern crate self as advent_of_code;
+
+pub mod generators;
+pub mod solutions;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
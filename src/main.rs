@@ -1,4 +1,8 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::{
+    all_days,
+    commands::{all, check, download, examples, generate, read, scaffold, solve, stars, time},
+    heap,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,8 +11,20 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        compare::Comparison,
+        input::InputSource,
+        parse_days, registry,
+        runner::{parse_part, parse_timeout, BenchConfig},
+        Day, MergePolicy, Year,
+    };
+    use std::{
+        collections::HashSet, convert::Infallible, ffi::OsString, path::PathBuf, process,
+        time::Duration,
+    };
+
+    /// Default percentage above which `time --compare` flags a slowdown as regression.
+    const DEFAULT_REGRESSION_THRESHOLD: f64 = 5.0;
 
     pub enum AppArguments {
         Download {
@@ -17,44 +33,184 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            write: Option<Vec<usize>>,
+            tests: bool,
+            overwrite: bool,
+        },
+        Generate {
+            days: HashSet<Day>,
+            seed: u64,
+            size: Option<usize>,
+            output: Option<PathBuf>,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            days: HashSet<Day>,
+            part: Option<u8>,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            store: bool,
+            watch: bool,
+            test: bool,
+            input: InputSource,
+            examples: bool,
+            timeout: Option<Duration>,
+            alloc: bool,
+            variants: bool,
         },
         All {
-            release: bool,
+            days: Option<HashSet<Day>>,
+            part: Option<u8>,
+            timeout: Option<Duration>,
+            alloc: bool,
+            variants: bool,
+        },
+        Check {
+            timeout: Option<Duration>,
+        },
+        Stars {
+            aoc: bool,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<HashSet<Day>>,
+            part: Option<u8>,
             store: bool,
+            bench: BenchConfig,
+            compare: Option<Comparison>,
+            timeout: Option<Duration>,
+            alloc: bool,
+            variants: bool,
+            merge: MergePolicy,
+            report: Option<PathBuf>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    /// Removes a flag with an optional, attached value from the arguments, e.g. `--example=2`.
+    /// Returns `None` if the flag is not present, `Some(None)` if it is present without a value.
+    /// A detached value is an error, unless it is a day expression, e.g. `--compare 5` compares day 5.
+    fn take_attached_value(
+        args: &mut Vec<OsString>,
+        flag: &str,
+    ) -> Result<Option<Option<String>>, String> {
+        let Some(index) = args.iter().position(|x| {
+            x.to_str()
+                .and_then(|x| x.strip_prefix(flag))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('='))
+        }) else {
+            return Ok(None);
+        };
+        let Ok(arg) = args.remove(index).into_string() else {
+            return Ok(None);
+        };
+
+        if let Some((_, value)) = arg.split_once('=') {
+            return Ok(Some(Some(value.to_string())));
+        }
+
+        match args.get(index).and_then(|x| x.to_str()) {
+            Some(next) if !next.starts_with('-') && parse_days(next).is_err() => Err(format!(
+                "`{flag}` takes an attached value, e.g. `{flag}={next}`."
+            )),
+            _ => Ok(Some(None)),
+        }
+    }
+
+    /// Parses a comma-separated list of code block numbers, e.g. `1,3`.
+    fn parse_blocks(s: &str) -> Result<Vec<usize>, String> {
+        s.split(',')
+            .map(|x| {
+                x.trim()
+                    .parse()
+                    .map_err(|_| format!("invalid block number `{x}`"))
+            })
+            .collect()
+    }
+
+    /// Parses the command line. Every command applies to a single year, which is read from `--year`.
+    /// Falls back to the `AOC_YEAR` environment variable if the option is not passed.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
+        let mut raw_args: Vec<OsString> = std::env::args_os().skip(1).collect();
+        // NOTE: pico-args does not support flags with optional values.
+        let compare = take_attached_value(&mut raw_args, "--compare")?;
+        let example = take_attached_value(&mut raw_args, "--example")?;
+
+        let mut args = pico_args::Arguments::from_vec(raw_args);
+
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or(
+                "no year specified. Pass `--year <year>` or set `AOC_YEAR` to a valid year.",
+            )?,
+        };
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                part: args.opt_value_from_fn("--part", parse_part)?,
+                timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+                alloc: args.contains("--alloc"),
+                variants: args.contains("--variants"),
+                // NOTE: free arguments are parsed last, so option values are not mistaken for days.
+                days: args.opt_free_from_fn(parse_days)?,
+            },
+            Some("check") => AppArguments::Check {
+                timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            },
+            Some("stars") => AppArguments::Stars {
+                aoc: args.contains("--aoc"),
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
 
+                let mut bench = BenchConfig::default();
+                if let Some(warmup) = args.opt_value_from_str("--warmup")? {
+                    bench.warmup = warmup;
+                }
+                if let Some(millis) = args.opt_value_from_str("--bench-time")? {
+                    bench.budget = Duration::from_millis(millis);
+                }
+                bench.iterations = args.opt_value_from_str("--iterations")?;
+
+                let threshold = args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(DEFAULT_REGRESSION_THRESHOLD);
+
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+                let merge = args.opt_value_from_str("--merge")?.unwrap_or_default();
+                if part.is_some() && merge == MergePolicy::Complete {
+                    return Err(
+                        "`--merge complete` can't be combined with `--part`, a single part never completes a day."
+                            .into(),
+                    );
+                }
+
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    part,
                     store,
+                    bench,
+                    compare: compare.map(|reference| Comparison {
+                        reference,
+                        threshold,
+                    }),
+                    timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+                    alloc: args.contains("--alloc"),
+                    variants: args.contains("--variants"),
+                    merge,
+                    report: args.opt_value_from_os_str("--report", |x| {
+                        Ok::<_, Infallible>(PathBuf::from(x))
+                    })?,
+                    days: args.opt_free_from_fn(parse_days)?,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -63,17 +219,120 @@ mod args {
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            Some("examples") => AppArguments::Examples {
                 day: args.free_from_str()?,
-                download: args.contains("--download"),
+                write: args.opt_value_from_fn("--write", parse_blocks)?,
+                tests: args.contains("--tests"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("generate") => {
+                let seed = args.opt_value_from_str("--seed")?.unwrap_or(0);
+                let size = args.opt_value_from_str("--size")?;
+                let output = args
+                    .opt_value_from_os_str("--output", |x| Ok::<_, Infallible>(PathBuf::from(x)))?;
+                let days = args.free_from_fn(parse_days)?;
+
+                if output.is_some() && days.len() > 1 {
+                    return Err("`--output` requires a single day.".into());
+                }
+
+                AppArguments::Generate {
+                    days,
+                    seed,
+                    size,
+                    output,
+                }
+            }
+            Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
             },
+            Some("solve") => {
+                let submit = args.opt_value_from_str("--submit")?;
+                let watch = args.contains("--watch");
+                let test = args.contains("--test");
+                let store = args.contains("--store");
+                let examples = args.contains("--examples");
+                let dhat = args.contains("--dhat");
+                let alloc = args.contains("--alloc");
+                let variants = args.contains("--variants");
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+
+                let input = InputSource::new(
+                    args.opt_value_from_str::<_, String>("--input")?.as_deref(),
+                    example.as_ref().map(Option::as_deref),
+                )?;
+
+                if watch && (submit.is_some() || dhat || store) {
+                    return Err(
+                        "`--submit`, `--dhat` and `--store` can't be combined with `--watch`."
+                            .into(),
+                    );
+                }
+                if test && !watch {
+                    return Err("`--test` requires `--watch`.".into());
+                }
+                if watch && input == InputSource::Stdin {
+                    return Err("`--input -` can't be combined with `--watch`.".into());
+                }
+                if examples && (watch || submit.is_some() || input != InputSource::Puzzle) {
+                    return Err(
+                        "`--examples` can't be combined with `--watch`, `--submit`, `--input` or `--example`."
+                            .into(),
+                    );
+                }
+                if alloc && (dhat || examples) {
+                    return Err("`--alloc` can't be combined with `--dhat` or `--examples`.".into());
+                }
+                if variants && (watch || examples) {
+                    return Err(
+                        "`--variants` can't be combined with `--watch` or `--examples`.".into(),
+                    );
+                }
+                if input != InputSource::Puzzle && (store || submit.is_some()) {
+                    return Err(
+                        "`--store` and `--submit` can only be used with the puzzle input.".into(),
+                    );
+                }
+                if let (Some(submit), Some(part)) = (submit, part) {
+                    if submit != part {
+                        return Err(format!(
+                            "`--submit {submit}` can't be combined with `--part {part}`."
+                        )
+                        .into());
+                    }
+                }
+
+                let release = args.contains("--release");
+                let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+                let days = args.free_from_fn(parse_days)?;
+
+                if days.len() > 1
+                    && (watch || examples || submit.is_some() || input != InputSource::Puzzle)
+                {
+                    return Err(
+                        "`--watch`, `--examples`, `--submit`, `--input` and `--example` require a single day."
+                            .into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    days,
+                    part,
+                    release,
+                    submit,
+                    dhat,
+                    store,
+                    watch,
+                    test,
+                    input,
+                    examples,
+                    timeout,
+                    alloc,
+                    variants,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -86,12 +345,40 @@ mod args {
             }
         };
 
+        // NOTE: without days, the days that don't implement the part are skipped.
+        if let AppArguments::Solve {
+            days,
+            part: Some(part),
+            ..
+        }
+        | AppArguments::All {
+            days: Some(days),
+            part: Some(part),
+            ..
+        }
+        | AppArguments::Time {
+            days: Some(days),
+            part: Some(part),
+            ..
+        } = &app_args
+        {
+            registry::check_part(year, days, *part)?;
+        }
+
         let remaining = args.finish();
+        // NOTE: a detached example number is left over after the days were parsed, e.g. `solve 12 --example 2`.
+        if let Some(n) = remaining.first().filter(|_| example == Some(None)) {
+            return Err(format!(
+                "`--example` takes an attached value, e.g. `--example={}`.",
+                n.to_string_lossy()
+            )
+            .into());
+        }
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
     }
 }
 
@@ -101,30 +388,107 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                days,
+                part,
+                timeout,
+                alloc,
+                variants,
+            } => {
+                if alloc {
+                    heap::enable();
+                }
+                all::handle(year, days.as_ref(), part, timeout, variants);
+            }
+            AppArguments::Check { timeout } => check::handle(year, timeout),
+            AppArguments::Stars { aoc } => stars::handle(year, aoc),
+            AppArguments::Time {
+                days,
+                part,
+                all,
+                store,
+                bench,
+                compare,
+                timeout,
+                alloc,
+                variants,
+                merge,
+                report,
+            } => {
+                if alloc {
+                    heap::enable();
+                }
+                time::handle(
+                    year,
+                    days.as_ref(),
+                    part,
+                    all,
+                    store,
+                    &bench,
+                    compare.as_ref(),
+                    timeout,
+                    variants,
+                    merge,
+                    report.as_deref(),
+                );
+            }
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Examples {
+                day,
+                write,
+                tests,
+                overwrite,
+            } => examples::handle(year, day, write.as_deref(), tests, overwrite),
+            AppArguments::Generate {
+                days,
+                seed,
+                size,
+                output,
+            } => generate::handle(year, &days, seed, size, output.as_deref()),
             AppArguments::Scaffold { day, download, overwrite } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(year, day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
                 }
             }
             AppArguments::Solve {
-                day,
+                days,
+                part,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                store,
+                watch,
+                test,
+                input,
+                examples,
+                timeout,
+                alloc,
+                variants,
+            } => {
+                // NOTE: days run in ascending order, `--watch` and `--examples` only ever get a single day.
+                for day in all_days().filter(|day| days.contains(day)) {
+                    if examples {
+                        solve::examples(year, day, part, store, timeout);
+                    } else if watch {
+                        solve::watch(year, day, part, release, test, &input, timeout, alloc);
+                    } else {
+                        solve::handle(
+                            year, day, part, release, dhat, submit, store, &input, timeout, alloc,
+                            variants,
+                        );
+                    }
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        scaffold::handle(year, day, false);
+                        download::handle(year, day);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,54 @@
+//! All solutions compiled into the library, so they can be run inside a single process.
+//! The modules include the solution binaries from `./src/bin`, `cargo scaffold` registers new days here.
+//!
+//! NOTE: as the library includes every registered day, a day that does not compile breaks every command,
+//! not just its own binary. Comment out the entry of a day while it's work in progress.
+//! The library's own tests don't include the days, so the tests of a day only run once, in its binary.
+use crate::template::registry::Solution;
+
+macro_rules! register {
+    ($($module:ident => $path:literal,)*) => {
+        $(
+            #[cfg(not(test))]
+            #[path = $path]
+            #[allow(dead_code)]
+            pub mod $module;
+        )*
+
+        /// All registered solutions, ordered by year and day.
+        #[cfg(not(test))]
+        pub static SOLUTIONS: &[Solution] = &[$($module::SOLUTION,)*];
+
+        /// The days are not compiled into the library's tests, see the module docs.
+        #[cfg(test)]
+        pub static SOLUTIONS: &[Solution] = &[];
+    };
+}
+
+register! {
+    y2024_day01 => "bin/2024-01.rs",
+    y2024_day02 => "bin/2024-02.rs",
+    y2024_day03 => "bin/2024-03.rs",
+    y2024_day04 => "bin/2024-04.rs",
+    y2024_day05 => "bin/2024-05.rs",
+    y2024_day06 => "bin/2024-06.rs",
+    y2024_day07 => "bin/2024-07.rs",
+    y2024_day08 => "bin/2024-08.rs",
+    y2024_day09 => "bin/2024-09.rs",
+    y2024_day10 => "bin/2024-10.rs",
+    y2024_day11 => "bin/2024-11.rs",
+    y2024_day12 => "bin/2024-12.rs",
+    y2024_day13 => "bin/2024-13.rs",
+    y2024_day14 => "bin/2024-14.rs",
+    y2024_day15 => "bin/2024-15.rs",
+    y2024_day16 => "bin/2024-16.rs",
+    y2024_day17 => "bin/2024-17.rs",
+    y2024_day18 => "bin/2024-18.rs",
+    y2024_day19 => "bin/2024-19.rs",
+    y2024_day20 => "bin/2024-20.rs",
+    y2024_day21 => "bin/2024-21.rs",
+    y2024_day22 => "bin/2024-22.rs",
+    y2024_day23 => "bin/2024-23.rs",
+    y2024_day24 => "bin/2024-24.rs",
+    y2024_day25 => "bin/2024-25.rs",
+}
@@ -0,0 +1,213 @@
+/// Ledger of accepted answers, stored per day in `data/YYYY/answers/DD.json`.
+/// Used by the `check` command to detect regressions in solutions.
+/// Answers for example files are stored separately, e.g. in `data/YYYY/answers/examples/DD-2.json`.
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day, Year};
+
+/// The accepted answers of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Rehydrate answers of a day from its JSON file. If not present, returns empty answers.
+    pub fn read_from_file(year: Year, day: Day) -> Result<Self, String> {
+        Self::read_from_path(&get_path(year, day))
+    }
+
+    /// Dehydrate answers of a day to its JSON file.
+    pub fn store_file(&self, year: Year, day: Day) -> Result<(), io::Error> {
+        self.store_path(&get_path(year, day))
+    }
+
+    /// Rehydrate the answers of an example file, e.g. `01-2` for `data/YYYY/examples/01-2.txt`.
+    /// Returns `None` if no answers were stored for the example.
+    pub fn read_example_from_file(year: Year, example: &str) -> Result<Option<Self>, String> {
+        let path = get_example_path(year, example);
+        if path.exists() {
+            Self::read_from_path(&path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Dehydrate the answers of an example file.
+    pub fn store_example_file(&self, year: Year, example: &str) -> Result<(), io::Error> {
+        self.store_path(&get_example_path(year, example))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => Answers::try_from(s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn store_path(&self, path: &Path) -> Result<(), io::Error> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Compares an answer to the accepted answer of a part.
+    pub fn check(&self, part: u8, answer: Option<&str>) -> AnswerStatus {
+        match (self.get(part), answer) {
+            (None, _) => AnswerStatus::Missing,
+            (Some(expected), Some(answer)) if expected == answer => AnswerStatus::Matching,
+            (Some(expected), _) => AnswerStatus::Mismatching {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+/// Result of comparing an answer against the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerStatus {
+    Matching,
+    Mismatching {
+        expected: String,
+    },
+    /// There is no accepted answer stored for the part.
+    Missing,
+}
+
+fn get_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("answers").join(format!("{day}.json"))
+}
+
+fn get_example_path(year: Year, example: &str) -> PathBuf {
+    data_dir(year)
+        .join("answers")
+        .join("examples")
+        .join(format!("{example}.json"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let to_json = |x: &Option<String>| match x {
+            Some(x) => JsonValue::String(x.clone()),
+            None => JsonValue::Null,
+        };
+
+        map.insert("part_1".into(), to_json(&value.part_1));
+        map.insert("part_2".into(), to_json(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answers.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answers.part_2 to be null or string.")?;
+
+        Ok(Answers {
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{AnswerStatus, Answers};
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "part_1": "1721", "part_2": null }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.part_1, Some("1721".into()));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_1: Some("1".into()),
+            part_2: Some("#..#\n.##.".into()),
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        Answers::try_from(r#"{ "part_1": 1 }"#.to_string()).unwrap();
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers {
+            part_1: Some("42".into()),
+            part_2: None,
+        };
+        assert_eq!(answers.check(1, Some("42")), AnswerStatus::Matching);
+        assert_eq!(
+            answers.check(1, Some("43")),
+            AnswerStatus::Mismatching {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            answers.check(1, None),
+            AnswerStatus::Mismatching {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(answers.check(2, Some("1")), AnswerStatus::Missing);
+    }
+}
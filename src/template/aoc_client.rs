@@ -0,0 +1,655 @@
+/// Built-in client for the Advent of Code website.
+/// Downloads inputs and puzzle descriptions, and submits answers.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::{data_dir, Day, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this crate to the Advent of Code servers if `AOC_USER_AGENT` is not set.
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session token was found in the environment or in `~/.adventofcode.session`.
+    MissingSession,
+    /// The server responded with a non-success status code.
+    BadStatus(u16),
+    /// The request could not be sent, e.g. because the server is unreachable.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session token found. Set `AOC_SESSION` or create the file `~/.adventofcode.session`."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status code {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// The verdict of the server on a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    /// The answer is wrong and the server did not tell in which direction.
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. Contains the time left to wait, if stated.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or can't be solved yet.
+    AlreadySolved,
+    /// The response could not be interpreted. Contains the text of the response.
+    Unknown(String),
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "⭐ That's the right answer!"),
+            SubmissionOutcome::Incorrect => write!(f, "✖ That's not the right answer."),
+            SubmissionOutcome::TooHigh => {
+                write!(f, "✖ That's not the right answer, it is too high.")
+            }
+            SubmissionOutcome::TooLow => {
+                write!(f, "✖ That's not the right answer, it is too low.")
+            }
+            SubmissionOutcome::RateLimited(Some(wait)) => {
+                write!(f, "⏳ You gave an answer too recently, wait {wait:?}.")
+            }
+            SubmissionOutcome::RateLimited(None) => {
+                write!(f, "⏳ You gave an answer too recently.")
+            }
+            SubmissionOutcome::AlreadySolved => {
+                write!(f, "This part was already solved or is not available yet.")
+            }
+            SubmissionOutcome::Unknown(text) => write!(f, "Unexpected response: {text}"),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: Year,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year, user_agent: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(user_agent)
+                .build(),
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the session token is read from `AOC_SESSION`, or from the file `~/.adventofcode.session`.
+    ///  2. the base url can be overridden with `AOC_BASE_URL`, e.g. to use a mock server.
+    ///  3. the user agent can be set with `AOC_USER_AGENT`, e.g. to the url of your repository.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let user_agent = env::var("AOC_USER_AGENT").unwrap_or_else(|_| DEFAULT_USER_AGENT.into());
+        Ok(Self::new(&base_url, &session, year, &user_agent))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn get_input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the puzzle description of a day, converted to markdown.
+    pub fn get_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+        Ok(puzzle_to_markdown(&html))
+    }
+
+    /// Submits an answer for a part and returns the verdict of the server.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        Ok(parse_submission(&response.into_string()?))
+    }
+
+    /// Fetches the calendar of the year and returns the number of stars collected per day.
+    pub fn get_stars(&self) -> Result<Vec<(Day, u8)>, AocClientError> {
+        let html = self.get(&format!("{}/{}", self.base_url, self.year))?;
+        Ok(parse_calendar(&html))
+    }
+
+    /// Downloads input and puzzle description of a day to the data directory.
+    pub fn download(&self, day: Day) -> Result<(), AocClientError> {
+        let input_path = get_input_path(self.year, day);
+        let puzzle_path = get_puzzle_path(self.year, day);
+
+        for path in [&input_path, &puzzle_path] {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        fs::write(&input_path, self.get_input(day)?)?;
+        fs::write(&puzzle_path, self.get_puzzle(day)?)?;
+
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        );
+        println!(
+            "🎄 Successfully wrote puzzle to \"{}\".",
+            puzzle_path.display()
+        );
+        Ok(())
+    }
+
+    /// Downloads the puzzle description of a day and returns it.
+    pub fn read(&self, day: Day) -> Result<String, AocClientError> {
+        let puzzle = self.get_puzzle(day)?;
+        let puzzle_path = get_puzzle_path(self.year, day);
+        if let Some(dir) = puzzle_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(puzzle_path, &puzzle)?;
+        Ok(puzzle)
+    }
+}
+
+fn get_input_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("inputs").join(format!("{day}.txt"))
+}
+
+fn get_puzzle_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("puzzles").join(format!("{day}.md"))
+}
+
+fn read_session() -> Option<String> {
+    let session = env::var("AOC_SESSION").ok().or_else(|| {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()
+    })?;
+
+    let session = session.trim();
+
+    if session.is_empty() {
+        None
+    } else {
+        Some(session.to_string())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Interprets the response page of an answer submission.
+pub fn parse_submission(html: &str) -> SubmissionOutcome {
+    let text = strip_tags(
+        extract_elements(html, "article")
+            .first()
+            .map_or(html, |x| x),
+    );
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        SubmissionOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            SubmissionOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmissionOutcome::TooLow
+        } else {
+            SubmissionOutcome::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        SubmissionOutcome::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmissionOutcome::AlreadySolved
+    } else {
+        SubmissionOutcome::Unknown(text)
+    }
+}
+
+/// Reads the stars per day from the labels of a calendar page, e.g. `aria-label="Day 3, one star"`.
+/// Days that are not unlocked yet are not part of the calendar.
+pub fn parse_calendar(html: &str) -> Vec<(Day, u8)> {
+    let mut days: Vec<(Day, u8)> = html
+        .split("aria-label=\"Day ")
+        .skip(1)
+        .filter_map(|label| {
+            let label = &label[..label.find('"')?];
+            let (day, stars) = label.split_once(',').unwrap_or((label, ""));
+            let stars = match stars.trim() {
+                "two stars" => 2,
+                "one star" => 1,
+                _ => 0,
+            };
+            Some((day.trim().parse().ok()?, stars))
+        })
+        .collect();
+
+    days.sort_unstable_by_key(|(day, _)| *day);
+    days.dedup_by_key(|(day, _)| *day);
+    days
+}
+
+/// Parses the wait time of a rate-limit message, e.g. "You have 4m 12s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 60 * 60),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Returns the inner HTML of all elements with the given tag name.
+fn extract_elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+
+    let mut elements = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let Some(content_start) = rest[start..].find('>').map(|x| start + x + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..]
+            .find(&close)
+            .map(|x| content_start + x)
+        else {
+            break;
+        };
+        elements.push(&rest[content_start..end]);
+        rest = &rest[end + close.len()..];
+    }
+
+    elements
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    decode_entities(&text)
+}
+
+/// Converts the puzzle descriptions (`<article class="day-desc">`) of a puzzle page to markdown.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    extract_elements(html, "article")
+        .iter()
+        .map(|article| html_to_markdown(article).trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+/// Minimal HTML to markdown conversion that covers the elements used in puzzle descriptions.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_pre = false;
+    let mut link_targets: Vec<String> = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            out.push_str(&decode_entities(rest));
+            break;
+        };
+
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>').map(|x| start + x) else {
+            out.push_str(&decode_entities(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        let name = tag
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match name.as_str() {
+            "h2" => out.push_str("## "),
+            "/h2" | "/p" => out.push_str("\n\n"),
+            "/ul" => out.push('\n'),
+            "pre" => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            "/pre" => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            "code" | "/code" if !in_pre => out.push('`'),
+            "em" | "/em" if !in_pre => out.push('*'),
+            "li" => out.push_str("- "),
+            "/li" => out.push('\n'),
+            "a" => {
+                link_targets.push(get_attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            "/a" => {
+                let href = link_targets.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    collapse_blank_lines(&out)
+}
+
+/// Reduces runs of blank lines to a single blank line.
+fn collapse_blank_lines(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        if c == '\n' && out.ends_with("\n\n") {
+            continue;
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..end]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    use super::{
+        parse_calendar, parse_submission, puzzle_to_markdown, AocClient, AocClientError,
+        SubmissionOutcome, DEFAULT_USER_AGENT,
+    };
+    use crate::{day, year};
+
+    /// Serves the given responses in order on a local port.
+    /// Returns the base url and a handle that yields the received requests.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut body_buf = vec![0; content_length];
+                reader.read_exact(&mut body_buf).unwrap();
+                request.push_str(&String::from_utf8(body_buf).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} STATUS\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    const PUZZLE_PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present, see <a href="/2024/about">here</a>.</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The total distance is <code><em>11</em></code>&lt;3.</p>
+<ul><li>one</li><li>two</li></ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+</main></body></html>"#;
+
+    const CALENDAR_PAGE: &str = r#"<main><pre class="calendar">
+<a aria-label="Day 3" href="/2024/day/3" class="calendar-day3"><span class="calendar-day"> 3</span></a>
+<a aria-label="Day 2, one star" href="/2024/day/2" class="calendar-day2 calendar-complete"><span class="calendar-day"> 2</span></a>
+<a aria-label="Day 1, two stars" href="/2024/day/1" class="calendar-day1 calendar-verycomplete"><span class="calendar-day"> 1</span></a>
+</pre></main>"#;
+    const CORRECT_PAGE: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.</p></article></main>";
+    const TOO_HIGH_PAGE: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article></main>";
+    const TOO_LOW_PAGE: &str = "<main><article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article></main>";
+    const INCORRECT_PAGE: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+    const RATE_LIMITED_PAGE: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait. [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article></main>";
+    const ALREADY_SOLVED_PAGE: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article></main>";
+
+    #[test]
+    fn parses_submission_outcomes() {
+        assert_eq!(parse_submission(CORRECT_PAGE), SubmissionOutcome::Correct);
+        assert_eq!(parse_submission(TOO_HIGH_PAGE), SubmissionOutcome::TooHigh);
+        assert_eq!(parse_submission(TOO_LOW_PAGE), SubmissionOutcome::TooLow);
+        assert_eq!(
+            parse_submission(INCORRECT_PAGE),
+            SubmissionOutcome::Incorrect
+        );
+        assert_eq!(
+            parse_submission(RATE_LIMITED_PAGE),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(252)))
+        );
+        assert_eq!(
+            parse_submission(ALREADY_SOLVED_PAGE),
+            SubmissionOutcome::AlreadySolved
+        );
+        assert_eq!(
+            parse_submission("<article><p>Something   else.</p></article>"),
+            SubmissionOutcome::Unknown("Something else.".into())
+        );
+    }
+
+    #[test]
+    fn parses_rate_limits_without_minutes() {
+        let page =
+            "<article><p>You gave an answer too recently. You have 35s left to wait.</p></article>";
+        assert_eq!(
+            parse_submission(page),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(35)))
+        );
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "The *Chief Historian* is always present, see [here](/2024/about).",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "```",
+            "",
+            "The total distance is `*11*`<3.",
+            "",
+            "- one",
+            "- two",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(puzzle_to_markdown(PUZZLE_PAGE), expected);
+    }
+
+    #[test]
+    fn downloads_input_and_puzzle() {
+        let (base_url, server) = mock_server(vec![(200, "3   4\n4   3\n"), (200, PUZZLE_PAGE)]);
+        let client = AocClient::new(&base_url, "secret", year!(2024), DEFAULT_USER_AGENT);
+
+        assert_eq!(client.get_input(day!(1)).unwrap(), "3   4\n4   3\n");
+        assert!(client
+            .get_puzzle(day!(1))
+            .unwrap()
+            .starts_with("## --- Day 1"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].contains(&format!("User-Agent: {DEFAULT_USER_AGENT}")));
+        assert!(requests[1].starts_with("GET /2024/day/1 "));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = mock_server(vec![(200, TOO_LOW_PAGE)]);
+        let client = AocClient::new(&base_url, "secret", year!(2024), DEFAULT_USER_AGENT);
+
+        assert_eq!(
+            client.submit(day!(7), 2, "1234").unwrap(),
+            SubmissionOutcome::TooLow
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/7/answer "));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn parses_calendar() {
+        assert_eq!(
+            parse_calendar(CALENDAR_PAGE),
+            vec![(day!(1), 2), (day!(2), 1), (day!(3), 0)]
+        );
+        assert!(parse_calendar("<main></main>").is_empty());
+    }
+
+    #[test]
+    fn fetches_stars() {
+        let (base_url, server) = mock_server(vec![(200, CALENDAR_PAGE)]);
+        let client = AocClient::new(&base_url, "secret", year!(2024), DEFAULT_USER_AGENT);
+
+        assert_eq!(client.get_stars().unwrap().len(), 3);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024 "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn errors_on_bad_status() {
+        let (base_url, server) = mock_server(vec![(404, "not found")]);
+        let client = AocClient::new(&base_url, "secret", year!(2024), DEFAULT_USER_AGENT);
+
+        assert!(matches!(
+            client.get_input(day!(25)),
+            Err(AocClientError::BadStatus(404))
+        ));
+        server.join().unwrap();
+    }
+}
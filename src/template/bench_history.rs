@@ -0,0 +1,523 @@
+/// Exports stored timings as CSV, a self-contained HTML page and an SVG bar chart.
+/// This allows sharing performance overviews outside of the readme, see `cargo time --report <dir>`.
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::timings::{format_nanos, Timing, TimingRun, Timings};
+use crate::template::{git, Year};
+
+const CHART_HEIGHT: f64 = 320.0;
+const CHART_MARGIN_LEFT: f64 = 70.0;
+const CHART_MARGIN_TOP: f64 = 40.0;
+const CHART_MARGIN_BOTTOM: f64 = 60.0;
+const CHART_DAY_WIDTH: f64 = 36.0;
+const BAR_WIDTH: f64 = 13.0;
+/// The chart is at least as wide as this number of days, so the legend fits.
+const MIN_CHART_DAYS: usize = 8;
+
+const PART_COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+const FAILURE_COLOR: &str = "#e15759";
+const HISTORY_COLOR: &str = "#555555";
+
+/// Writes the CSV, HTML and SVG files of a year to `dir`, e.g. `benchmarks-2024.csv`.
+/// The history is written to a separate CSV file if there is one. Returns the paths of the written files.
+pub fn write(year: Year, timings: &Timings, dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    let mut files = vec![
+        (format!("benchmarks-{year}.csv"), csv(timings)),
+        (format!("benchmarks-{year}.svg"), svg(year, timings)),
+        (format!("benchmarks-{year}.html"), html(year, timings)),
+    ];
+
+    if !timings.history.is_empty() {
+        files.push((
+            format!("benchmarks-{year}-history.csv"),
+            history_csv(timings),
+        ));
+    }
+
+    files
+        .into_iter()
+        .map(|(name, content)| {
+            let path = dir.join(name);
+            fs::write(&path, content)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// A cell of the report: the duration of a part, or the status of a part that did not finish.
+enum Cell {
+    Duration(f64),
+    Failure(String),
+}
+
+fn cell(timing: &Timing, part: u8) -> Option<Cell> {
+    match (timing.part_nanos(part), timing.failure(part)) {
+        (Some(nanos), _) => Some(Cell::Duration(nanos)),
+        (None, Some(status)) => Some(Cell::Failure(status.to_string())),
+        (None, None) => None,
+    }
+}
+
+/// Formats a unix timestamp as UTC date, e.g. `2024-12-24`.
+fn format_date(timestamp: u64) -> String {
+    // see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    #[allow(clippy::cast_possible_wrap)]
+    let z = (timestamp / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn run_label(run: &TimingRun) -> String {
+    let commit = run.commit.as_deref().map_or("unknown", git::short_hash);
+    format!("{commit} ({})", format_date(run.timestamp))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Latest timings, one row per part: `day,part,nanos,status,allocations,alloc_bytes,peak_bytes`.
+fn csv(timings: &Timings) -> String {
+    let mut s = String::from("day,part,nanos,status,allocations,alloc_bytes,peak_bytes\n");
+
+    for timing in &timings.data {
+        for part in [1, 2] {
+            let (nanos, status) = match cell(timing, part) {
+                Some(Cell::Duration(nanos)) => (format!("{nanos}"), "solved".to_string()),
+                Some(Cell::Failure(status)) => (String::new(), status),
+                None => continue,
+            };
+
+            let alloc = timing.allocations(part).map_or(",,".into(), |a| {
+                format!("{},{},{}", a.allocations, a.bytes, a.peak_bytes)
+            });
+
+            let _ = writeln!(s, "{},{part},{nanos},{status},{alloc}", timing.day);
+        }
+    }
+
+    s
+}
+
+/// Timings of previous runs, one row per run and part: `timestamp,commit,day,part,nanos,status`.
+fn history_csv(timings: &Timings) -> String {
+    let mut s = String::from("timestamp,commit,day,part,nanos,status\n");
+
+    for run in &timings.history {
+        let commit = run.commit.as_deref().unwrap_or_default();
+        for timing in &run.data {
+            for part in [1, 2] {
+                let (nanos, status) = match cell(timing, part) {
+                    Some(Cell::Duration(nanos)) => (format!("{nanos}"), "solved".to_string()),
+                    Some(Cell::Failure(status)) => (String::new(), status),
+                    None => continue,
+                };
+
+                let _ = writeln!(
+                    s,
+                    "{},{commit},{},{part},{nanos},{status}",
+                    run.timestamp, timing.day
+                );
+            }
+        }
+    }
+
+    s
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Log-scaled vertical axis of the chart, spanning full powers of ten.
+struct LogScale {
+    min_exp: i32,
+    max_exp: i32,
+}
+
+impl LogScale {
+    /// Creates a scale that fits all `values`. Returns `None` if there are no values.
+    #[allow(clippy::cast_possible_truncation)]
+    fn fit(values: impl Iterator<Item = f64>) -> Option<Self> {
+        let (min, max) = values
+            .filter(|v| *v > 0.0)
+            .fold(None, |acc: Option<(f64, f64)>, v| match acc {
+                Some((min, max)) => Some((min.min(v), max.max(v))),
+                None => Some((v, v)),
+            })?;
+
+        let min_exp = min.log10().floor() as i32;
+        let max_exp = (max.log10().ceil() as i32).max(min_exp + 1);
+        Some(Self { min_exp, max_exp })
+    }
+
+    /// Returns the y coordinate of a value in nanoseconds.
+    fn y(&self, nanos: f64) -> f64 {
+        let range = f64::from(self.max_exp - self.min_exp);
+        let share = (nanos.max(1.0).log10() - f64::from(self.min_exp)) / range;
+        CHART_MARGIN_TOP + CHART_HEIGHT * (1.0 - share.clamp(0.0, 1.0))
+    }
+}
+
+/// Bar chart with a bar per day and part. Previous runs are drawn as dots on top of the bars.
+fn svg(year: Year, timings: &Timings) -> String {
+    let history_nanos = timings
+        .history
+        .iter()
+        .flat_map(|run| run.data.iter())
+        .flat_map(|t| [t.part_nanos(1), t.part_nanos(2)]);
+
+    let latest_nanos = timings
+        .data
+        .iter()
+        .flat_map(|t| [t.part_nanos(1), t.part_nanos(2)]);
+
+    let scale = LogScale::fit(latest_nanos.chain(history_nanos).flatten());
+
+    #[allow(clippy::cast_precision_loss)]
+    let width =
+        CHART_MARGIN_LEFT + CHART_DAY_WIDTH * timings.data.len().max(MIN_CHART_DAYS) as f64 + 20.0;
+    let height = CHART_MARGIN_TOP + CHART_HEIGHT + CHART_MARGIN_BOTTOM;
+    let bottom = CHART_MARGIN_TOP + CHART_HEIGHT;
+
+    let mut s = String::new();
+    let _ = writeln!(
+        s,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+    );
+    let _ = writeln!(
+        s,
+        r#"<text x="{CHART_MARGIN_LEFT}" y="20" font-size="14" font-weight="bold">{year} Benchmarks</text>"#
+    );
+
+    let Some(scale) = scale else {
+        let _ = writeln!(
+            s,
+            r#"<text x="{CHART_MARGIN_LEFT}" y="{}">No stored timings.</text>"#,
+            CHART_MARGIN_TOP + 20.0
+        );
+        s.push_str("</svg>\n");
+        return s;
+    };
+
+    // grid lines and labels for every power of ten.
+    for exp in scale.min_exp..=scale.max_exp {
+        let nanos = 10_f64.powi(exp);
+        let y = scale.y(nanos);
+        let _ = writeln!(
+            s,
+            r##"<line x1="{CHART_MARGIN_LEFT}" x2="{}" y1="{y:.1}" y2="{y:.1}" stroke="#dddddd"/>"##,
+            width - 20.0
+        );
+        let _ = writeln!(
+            s,
+            r#"<text x="{}" y="{:.1}" text-anchor="end">{}</text>"#,
+            CHART_MARGIN_LEFT - 6.0,
+            y + 4.0,
+            format_nanos(nanos)
+        );
+    }
+
+    for (index, timing) in timings.data.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let day_x = CHART_MARGIN_LEFT + CHART_DAY_WIDTH * index as f64 + 4.0;
+
+        for part in [1_u8, 2] {
+            let x = day_x + BAR_WIDTH * f64::from(part - 1) + 1.0;
+            let color = PART_COLORS[usize::from(part - 1)];
+
+            match cell(timing, part) {
+                Some(Cell::Duration(nanos)) => {
+                    let y = scale.y(nanos);
+                    let _ = writeln!(
+                        s,
+                        r#"<rect x="{x:.1}" y="{y:.1}" width="{}" height="{:.1}" fill="{color}"><title>Day {} Part {part}: {}</title></rect>"#,
+                        BAR_WIDTH - 2.0,
+                        bottom - y,
+                        timing.day,
+                        format_nanos(nanos)
+                    );
+                }
+                Some(Cell::Failure(status)) => {
+                    let _ = writeln!(
+                        s,
+                        r#"<text x="{:.1}" y="{:.1}" fill="{FAILURE_COLOR}" text-anchor="middle">✖<title>Day {} Part {part}: {}</title></text>"#,
+                        x + BAR_WIDTH / 2.0 - 1.0,
+                        bottom - 4.0,
+                        timing.day,
+                        status.replace('_', " ")
+                    );
+                }
+                None => {}
+            }
+
+            for run in &timings.history {
+                let Some(nanos) = run
+                    .data
+                    .iter()
+                    .find(|t| t.day == timing.day)
+                    .and_then(|t| t.part_nanos(part))
+                else {
+                    continue;
+                };
+
+                let _ = writeln!(
+                    s,
+                    r#"<circle cx="{:.1}" cy="{:.1}" r="2.5" fill="{HISTORY_COLOR}" fill-opacity="0.6"><title>Day {} Part {part} @ {}: {}</title></circle>"#,
+                    x + BAR_WIDTH / 2.0 - 1.0,
+                    scale.y(nanos),
+                    timing.day,
+                    escape(&run_label(run)),
+                    format_nanos(nanos)
+                );
+            }
+        }
+
+        let _ = writeln!(
+            s,
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+            day_x + BAR_WIDTH,
+            bottom + 16.0,
+            timing.day.into_inner()
+        );
+    }
+
+    // legend
+    let legend_y = bottom + 40.0;
+    for (index, label) in ["Part 1", "Part 2"].iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = CHART_MARGIN_LEFT + 80.0 * index as f64;
+        let _ = writeln!(
+            s,
+            r#"<rect x="{x}" y="{}" width="10" height="10" fill="{}"/><text x="{}" y="{legend_y}">{label}</text>"#,
+            legend_y - 9.0,
+            PART_COLORS[index],
+            x + 14.0
+        );
+    }
+    if !timings.history.is_empty() {
+        let x = CHART_MARGIN_LEFT + 160.0;
+        let _ = writeln!(
+            s,
+            r#"<circle cx="{}" cy="{}" r="3" fill="{HISTORY_COLOR}" fill-opacity="0.6"/><text x="{}" y="{legend_y}">Previous runs</text>"#,
+            x + 5.0,
+            legend_y - 4.0,
+            x + 14.0
+        );
+    }
+
+    s.push_str("</svg>\n");
+    s
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Self-contained page with the chart, the latest timings and a summary of previous runs.
+fn html(year: Year, timings: &Timings) -> String {
+    let mut s = String::new();
+
+    let _ = writeln!(
+        s,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{year} Benchmarks</title>"
+    );
+    s.push_str(
+        "<style>body { font-family: sans-serif; margin: 2em; } \
+         table { border-collapse: collapse; margin: 1em 0; } \
+         th, td { border: 1px solid #ddd; padding: 4px 10px; text-align: right; } \
+         th { background: #f4f4f4; }</style>\n</head>\n<body>\n",
+    );
+    let _ = writeln!(s, "<h1>{year} Benchmarks</h1>");
+    s.push_str(&svg(year, timings));
+
+    let show_heap = timings
+        .data
+        .iter()
+        .flat_map(|t| &t.parts)
+        .any(|p| p.alloc.is_some());
+    s.push_str("<h2>Latest timings</h2>\n<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th>");
+    if show_heap {
+        s.push_str("<th>Part 1 Heap</th><th>Part 2 Heap</th>");
+    }
+    s.push_str("</tr>\n");
+
+    for timing in &timings.data {
+        let _ = write!(s, "<tr><td>{}</td>", timing.day.into_inner());
+        for part in [1, 2] {
+            let value = match cell(timing, part) {
+                Some(Cell::Duration(nanos)) => format_nanos(nanos),
+                Some(Cell::Failure(status)) => status.replace('_', " "),
+                None => "-".into(),
+            };
+            let _ = write!(s, "<td>{value}</td>");
+        }
+        if show_heap {
+            for part in [1, 2] {
+                let value = timing
+                    .allocations(part)
+                    .map_or_else(|| "-".into(), |a| a.summary());
+                let _ = write!(s, "<td>{value}</td>");
+            }
+        }
+        s.push_str("</tr>\n");
+    }
+
+    let _ = writeln!(
+        s,
+        "</table>\n<p><strong>Total: {:.2}ms</strong></p>",
+        timings.total_millis()
+    );
+
+    if !timings.history.is_empty() {
+        s.push_str(
+            "<h2>Previous runs</h2>\n<table>\n<tr><th>Run</th><th>Days</th><th>Total</th></tr>\n",
+        );
+        for run in timings.history.iter().rev() {
+            let total_millis =
+                run.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64;
+            let _ = writeln!(
+                s,
+                "<tr><td>{}</td><td>{}</td><td>{total_millis:.2}ms</td></tr>",
+                escape(&run_label(run)),
+                run.data.len()
+            );
+        }
+        s.push_str("</table>\n");
+    }
+
+    s.push_str("</body>\n</html>\n");
+    s
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{csv, format_date, history_csv, html, svg, LogScale};
+    use crate::{
+        day,
+        template::report::PartStatus,
+        template::timings::{PartTiming, Timing, TimingRun, Timings},
+        year,
+    };
+
+    fn part(part: u8, nanos: f64, status: PartStatus) -> PartTiming {
+        PartTiming {
+            part,
+            nanos,
+            samples: Some(10),
+            status,
+            alloc: None,
+        }
+    }
+
+    fn get_mock_timings() -> Timings {
+        let timing = |day, part_1: f64, part_2: f64| Timing {
+            day,
+            parts: vec![
+                part(1, part_1, PartStatus::Solved),
+                part(2, part_2, PartStatus::Solved),
+            ],
+        };
+
+        let day_2 = Timing {
+            day: day!(2),
+            parts: vec![
+                part(1, 30e+6, PartStatus::Solved),
+                part(2, 10e+9, PartStatus::TimedOut),
+            ],
+        };
+
+        Timings {
+            data: vec![timing(day!(1), 10e+6, 20e+3), day_2],
+            history: vec![TimingRun {
+                timestamp: 1_703_376_000,
+                commit: Some("00c1b8f5d".into()),
+                environment: None,
+                data: vec![timing(day!(1), 12e+6, 25e+3)],
+            }],
+        }
+    }
+
+    #[test]
+    fn writes_csv() {
+        assert_eq!(
+            csv(&get_mock_timings()),
+            [
+                "day,part,nanos,status,allocations,alloc_bytes,peak_bytes",
+                "01,1,10000000,solved,,,",
+                "01,2,20000,solved,,,",
+                "02,1,30000000,solved,,,",
+                "02,2,,timed_out,,,",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn writes_history_csv() {
+        assert_eq!(
+            history_csv(&get_mock_timings()),
+            [
+                "timestamp,commit,day,part,nanos,status",
+                "1703376000,00c1b8f5d,01,1,12000000,solved",
+                "1703376000,00c1b8f5d,01,2,25000,solved",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_703_376_000), "2023-12-24");
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = LogScale::fit([20_000.0, 10_000_000.0].into_iter()).unwrap();
+        assert_eq!((scale.min_exp, scale.max_exp), (4, 7));
+        assert!(scale.y(10_000.0) > scale.y(100_000.0));
+        assert!(LogScale::fit(std::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn draws_bars_and_history() {
+        let s = svg(year!(2024), &get_mock_timings());
+        assert!(s.starts_with("<svg"));
+        assert_eq!(s.matches("<rect").count(), 3 + 2);
+        assert!(s.contains("Day 01 Part 1 @ 00c1b8f (2023-12-24): 12.0ms"));
+        assert!(s.contains("Day 02 Part 2: timed out"));
+    }
+
+    #[test]
+    fn writes_html() {
+        let s = html(year!(2024), &get_mock_timings());
+        assert!(s.contains("<svg"));
+        assert!(s.contains("<tr><td>2</td><td>30.0ms</td><td>timed out</td></tr>"));
+        assert!(s.contains("<td>00c1b8f (2023-12-24)</td>"));
+    }
+
+    #[test]
+    fn handles_empty_timings() {
+        let s = svg(year!(2024), &Timings::default());
+        assert!(s.contains("No stored timings."));
+    }
+}
@@ -1,5 +1,20 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{collections::HashSet, process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{all_days, run_multi::run_multi, Day, Year};
+
+/// Runs the solutions of `days`, or of every day if not set.
+/// Exits with a non-zero status code if a variant disagrees with its part.
+pub fn handle(
+    year: Year,
+    days: Option<&HashSet<Day>>,
+    part: Option<u8>,
+    timeout: Option<Duration>,
+    variants: bool,
+) {
+    let days_to_run = days.cloned().unwrap_or_else(|| all_days().collect());
+    let (_, variants_agree) = run_multi(year, &days_to_run, part, None, timeout, variants);
+
+    if !variants_agree {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,67 @@
+use std::{process, time::Duration};
+
+use crate::template::answers::{AnswerStatus, Answers};
+use crate::template::run_multi::run_solution;
+use crate::template::{all_days, try_read_file, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Runs every day of a year and compares the answers against the answer ledger.
+/// Exits with a non-zero status code if any answer does not match or an input can't be read.
+pub fn handle(year: Year, timeout: Option<Duration>) {
+    let mut matching = 0;
+    let mut mismatching = 0;
+    let mut missing = 0;
+    let mut failed = 0;
+
+    for day in all_days() {
+        let answers = Answers::read_from_file(year, day).unwrap_or_else(|e| {
+            eprintln!("Failed to read stored answers for day {day}: {e}");
+            Answers::default()
+        });
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        if let Err(e) = try_read_file("inputs", year, day) {
+            failed += 1;
+            println!("  ✘ could not read input file: {e}");
+            continue;
+        }
+
+        let (reports, _) = run_solution(year, day, None, None, timeout, false, false);
+
+        for part in [1, 2] {
+            let answer = reports
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.as_deref());
+
+            let answer_str = answer.unwrap_or("✖");
+
+            match answers.check(part, answer) {
+                AnswerStatus::Matching => {
+                    matching += 1;
+                    println!("  Part {part}: ✔ {answer_str}");
+                }
+                AnswerStatus::Mismatching { expected } => {
+                    mismatching += 1;
+                    println!(
+                        "  Part {part}: ✘ {ANSI_BOLD}{answer_str}{ANSI_RESET} {ANSI_ITALIC}(expected {expected}){ANSI_RESET}"
+                    );
+                }
+                AnswerStatus::Missing => {
+                    missing += 1;
+                    println!(
+                        "  Part {part}: ? {answer_str} {ANSI_ITALIC}(no stored answer){ANSI_RESET}"
+                    );
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Summary:{ANSI_RESET} {matching} matching, {mismatching} mismatching, {missing} missing, {failed} failed."
+    );
+
+    if mismatching > 0 || failed > 0 {
+        process::exit(1);
+    }
+}
@@ -1,14 +1,14 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client::AocClient, Day, Year};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(year: Year, day: Day) {
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to create Advent of Code client: {e}");
         process::exit(1);
-    }
+    });
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = client.download(day) {
+        eprintln!("failed to download day {day}: {e}");
         process::exit(1);
     };
 }
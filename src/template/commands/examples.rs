@@ -0,0 +1,322 @@
+use std::{fs, path::PathBuf, process};
+
+use crate::template::{
+    data_dir,
+    puzzle::{Puzzle, StatedAnswer},
+    Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Number of lines shown per code block when listing a puzzle's examples.
+const PREVIEW_LINES: usize = 4;
+
+/// An example file written from a code block of the puzzle.
+/// The part suffix is only set if several blocks are written, e.g. `01-2.txt`.
+struct ExampleFile {
+    block: usize,
+    suffix: Option<usize>,
+}
+
+impl ExampleFile {
+    fn file_name(&self, day: Day) -> String {
+        match self.suffix {
+            Some(suffix) => format!("{day}-{suffix}.txt"),
+            None => format!("{day}.txt"),
+        }
+    }
+}
+
+pub fn handle(year: Year, day: Day, write: Option<&[usize]>, tests: bool, overwrite: bool) {
+    let puzzle = match Puzzle::read_from_file(year, day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!(
+                "Failed to read puzzle description: {e}. Run `cargo download {day}` to download it."
+            );
+            process::exit(1);
+        }
+    };
+
+    print_examples(&puzzle);
+
+    let Some(blocks) = write else {
+        if tests {
+            eprintln!("Select the examples to generate tests for with `--write <blocks>`.");
+            process::exit(1);
+        }
+        return;
+    };
+
+    if let Some(index) = blocks.iter().find(|i| puzzle.block(**i).is_none()) {
+        eprintln!("Puzzle description has no code block [{index}].");
+        process::exit(1);
+    }
+
+    let files: Vec<ExampleFile> = blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| ExampleFile {
+            block: *block,
+            suffix: (blocks.len() > 1).then_some(i + 1),
+        })
+        .collect();
+
+    println!();
+    write_examples(&puzzle, year, day, &files, overwrite);
+
+    if tests {
+        let module_path = format!("src/bin/{year}-{day}.rs");
+        let answers: Vec<(&ExampleFile, &StatedAnswer)> = files
+            .iter()
+            .flat_map(|file| puzzle.answers_for(file.block).map(move |a| (file, a)))
+            .collect();
+
+        let result = fs::read_to_string(&module_path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| add_tests(&content, &generate_tests(&answers)));
+
+        match result {
+            Ok((content, added)) => {
+                if let Err(e) = fs::write(&module_path, content) {
+                    eprintln!("Failed to write module file: {e}");
+                    process::exit(1);
+                }
+                for name in added {
+                    println!("Added test `{name}` to \"{module_path}\"");
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to add tests: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn print_examples(puzzle: &Puzzle) {
+    if puzzle.blocks.is_empty() {
+        println!("Puzzle description contains no code blocks.");
+        return;
+    }
+
+    for block in &puzzle.blocks {
+        let lines: Vec<&str> = block.content.lines().collect();
+
+        println!(
+            "{ANSI_BOLD}[{}]{ANSI_RESET} {ANSI_ITALIC}part {}, {} lines{ANSI_RESET}",
+            block.index,
+            block.part,
+            lines.len()
+        );
+
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    ...");
+        }
+
+        for answer in puzzle.answers_for(block.index) {
+            println!(
+                "  Part {}: {ANSI_BOLD}{}{ANSI_RESET}",
+                answer.part, answer.value
+            );
+        }
+    }
+}
+
+fn write_examples(puzzle: &Puzzle, year: Year, day: Day, files: &[ExampleFile], overwrite: bool) {
+    let dir = data_dir(year).join("examples");
+
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Failed to create examples directory: {e}");
+        process::exit(1);
+    }
+
+    for file in files {
+        let path: PathBuf = dir.join(file.file_name(day));
+
+        // scaffolded example files are empty and can be replaced.
+        let has_content = fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+        if has_content && !overwrite {
+            eprintln!(
+                "Example file \"{}\" already exists, pass `--overwrite` to replace it.",
+                path.display()
+            );
+            process::exit(1);
+        }
+
+        // NOTE: `puzzle.block` was validated by the caller.
+        let content = &puzzle.block(file.block).unwrap().content;
+
+        match fs::write(&path, content) {
+            Ok(()) => println!(
+                "Wrote block [{}] to example file \"{}\"",
+                file.block,
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Generates a test function per stated answer. Returns pairs of test name and source code.
+fn generate_tests(answers: &[(&ExampleFile, &StatedAnswer)]) -> Vec<(String, String)> {
+    answers
+        .iter()
+        .map(|(file, answer)| {
+            let func = if answer.part == 1 {
+                "part_one"
+            } else {
+                "part_two"
+            };
+
+            let (name, input) = match file.suffix {
+                Some(suffix) => (
+                    format!("test_{func}_example_{suffix}"),
+                    format!(
+                        "&advent_of_code::template::read_file_part(\n            \"examples\", YEAR, DAY, {suffix},\n        )"
+                    ),
+                ),
+                None => (
+                    format!("test_{func}_example"),
+                    "&advent_of_code::template::read_file(\"examples\", YEAR, DAY)".to_string(),
+                ),
+            };
+
+            let expected = if answer.value.parse::<i64>().is_ok() {
+                answer.value.clone()
+            } else {
+                format!("{:?}.to_string()", answer.value)
+            };
+
+            let code = [
+                "    #[test]".to_string(),
+                format!("    fn {name}() {{"),
+                format!("        let result = {func}({input});"),
+                format!("        assert_eq!(result, Some({expected}));"),
+                "    }".to_string(),
+            ]
+            .join("\n");
+
+            (name, code)
+        })
+        .collect()
+}
+
+/// Appends tests to the `tests` module of a solution. Tests that already exist are skipped.
+/// Returns the updated source and the names of the added tests.
+fn add_tests(content: &str, tests: &[(String, String)]) -> Result<(String, Vec<String>), String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("mod tests {"))
+        .ok_or("could not find `tests` module.")?;
+
+    let end = start
+        + lines[start..]
+            .iter()
+            .rposition(|line| *line == "}")
+            .ok_or("could not find end of `tests` module.")?;
+
+    let mut added: Vec<String> = vec![];
+    let mut code: Vec<&str> = vec![];
+
+    for (name, test) in tests {
+        if content.contains(&format!("fn {name}()")) || added.contains(name) {
+            continue;
+        }
+        added.push(name.clone());
+        code.extend(["", test]);
+    }
+
+    lines.splice(end..end, code);
+
+    Ok((lines.join("\n") + "\n", added))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{add_tests, generate_tests, ExampleFile};
+    use crate::template::puzzle::StatedAnswer;
+
+    const MODULE: &str = "advent_of_code::solution!(2024, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(1, 1);
+    }
+}
+";
+
+    fn answer(part: u8, value: &str) -> StatedAnswer {
+        StatedAnswer {
+            block: 1,
+            part,
+            value: value.into(),
+        }
+    }
+
+    #[test]
+    fn generates_tests() {
+        let single = ExampleFile {
+            block: 1,
+            suffix: None,
+        };
+        let multi = ExampleFile {
+            block: 1,
+            suffix: Some(2),
+        };
+        let (a, b) = (answer(1, "11"), answer(2, "a,b"));
+        let tests = generate_tests(&[(&single, &a), (&multi, &b)]);
+
+        assert_eq!(tests[0].0, "test_part_one_example");
+        assert_eq!(
+            tests[0].1,
+            "    #[test]
+    fn test_part_one_example() {
+        let result = part_one(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));
+        assert_eq!(result, Some(11));
+    }"
+        );
+
+        assert_eq!(tests[1].0, "test_part_two_example_2");
+        assert_eq!(
+            tests[1].1,
+            "    #[test]
+    fn test_part_two_example_2() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            \"examples\", YEAR, DAY, 2,
+        ));
+        assert_eq!(result, Some(\"a,b\".to_string()));
+    }"
+        );
+    }
+
+    #[test]
+    fn adds_tests_to_module() {
+        let tests = vec![("test_new".to_string(), "    fn test_new() {}".to_string())];
+        let (content, added) = add_tests(MODULE, &tests).unwrap();
+
+        assert_eq!(added, vec!["test_new"]);
+        assert!(content.ends_with("    }\n\n    fn test_new() {}\n}\n"));
+
+        let (again, added) = add_tests(&content, &tests).unwrap();
+        assert!(added.is_empty());
+        assert_eq!(again, content);
+    }
+
+    #[test]
+    fn errors_if_tests_module_is_missing() {
+        assert!(add_tests("fn main() {}", &[]).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use std::{collections::HashSet, fs, path::Path, process};
+
+use crate::template::{all_days, data_dir, generator, Day, Year};
+
+/// Writes a generated input for each of `days`, to `output` or to `data/<year>/generated/<day>.txt`.
+/// The size defaults to the size of real puzzle inputs of a day. Answers the generator knows are printed.
+pub fn handle(
+    year: Year,
+    days: &HashSet<Day>,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<&Path>,
+) {
+    for day in all_days().filter(|day| days.contains(day)) {
+        let Some(generator) = generator::get(year, day) else {
+            eprintln!("Day {day} of {year} has no generator registered in \"src/generators.rs\".");
+            process::exit(1);
+        };
+
+        let path = output.map_or_else(
+            || data_dir(year).join("generated").join(format!("{day}.txt")),
+            Path::to_path_buf,
+        );
+
+        let generated = generator.generate(seed, size.unwrap_or_else(|| generator.default_size()));
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&path, generated.input + "\n"));
+
+        match result {
+            Ok(()) => {
+                println!("🎄 Successfully wrote input to \"{}\".", path.display());
+                for part in [1, 2] {
+                    if let Some(answer) = generated.answers.get(part) {
+                        println!("   Part {part}: {answer}");
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to write input for day {day}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod all;
+pub mod check;
 pub mod download;
+pub mod examples;
+pub mod generate;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
 pub mod time;
@@ -1,14 +1,17 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{data_dir, Day, Year};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+const SOLUTIONS_PATH: &str = "src/solutions.rs";
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -19,7 +22,7 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +30,60 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Adds the module of a day to the `register!` invocation in `src/solutions.rs`.
+/// Returns `false` if the day is already registered.
+fn register_solution(year: Year, day: Day) -> Result<bool, std::io::Error> {
+    let content = fs::read_to_string(SOLUTIONS_PATH)?;
+    let updated = add_registry_entry(&content, year, day).map_err(std::io::Error::other)?;
+
+    match updated {
+        Some(updated) => {
+            fs::write(SOLUTIONS_PATH, updated)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn add_registry_entry(content: &str, year: Year, day: Day) -> Result<Option<String>, String> {
+    let entry = format!("    y{year}_day{day} => \"bin/{year}-{day}.rs\",");
+
+    if content.lines().any(|line| line == entry) {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|line| *line == "register! {")
+        .ok_or("could not find `register!` invocation.")?;
+
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or("could not find end of `register!` invocation.")?;
+
+    // NOTE: entries are zero-padded, so sorting them lexically keeps them ordered by year and day.
+    lines.insert(end, &entry);
+    lines[start + 1..=end].sort_unstable();
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let data_dir = data_dir(year);
+    let input_path = data_dir.join("inputs").join(format!("{day}.txt"));
+    let example_path = data_dir.join("examples").join(format!("{day}.txt"));
+    let module_path = format!("src/bin/{year}-{day}.rs");
+
+    for path in [&input_path, &example_path] {
+        if let Some(Err(e)) = path.parent().map(fs::create_dir_all) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -42,6 +95,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("%YEAR%", &year.to_string())
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -54,9 +108,20 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match register_solution(year, day) {
+        Ok(true) => {
+            println!("Registered module in \"{SOLUTIONS_PATH}\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register module: {e}");
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -66,7 +131,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -75,5 +140,34 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::add_registry_entry;
+    use crate::{day, year};
+
+    #[test]
+    fn registers_new_days_in_order() {
+        let content = "use foo;\n\nregister! {\n    y2023_day05 => \"bin/2023-05.rs\",\n    y2024_day01 => \"bin/2024-01.rs\",\n    y2024_day03 => \"bin/2024-03.rs\",\n}\n";
+        let updated = add_registry_entry(content, year!(2024), day!(2))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated,
+            "use foo;\n\nregister! {\n    y2023_day05 => \"bin/2023-05.rs\",\n    y2024_day01 => \"bin/2024-01.rs\",\n    y2024_day02 => \"bin/2024-02.rs\",\n    y2024_day03 => \"bin/2024-03.rs\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn skips_registered_days() {
+        let content = "register! {\n    y2024_day01 => \"bin/2024-01.rs\",\n}\n";
+        assert_eq!(add_registry_entry(content, year!(2024), day!(1)), Ok(None));
+    }
+
+    #[test]
+    fn errors_if_invocation_is_missing() {
+        assert!(add_registry_entry("// empty", year!(2024), day!(1)).is_err());
+    }
 }
@@ -1,9 +1,42 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+    process::{self, Command, Stdio},
+    sync::Arc,
+    time::Duration,
+};
 
-use crate::template::Day;
+use crate::template::{
+    answers::{AnswerStatus, Answers},
+    data_dir,
+    input::InputSource,
+    registry,
+    report::{PartReport, PartStatus},
+    runner::{print_report, run_part_once},
+    try_read_file, try_read_file_part,
+    watch::Watcher,
+    Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Day,
+    part: Option<u8>,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    store: bool,
+    input: &InputSource,
+    timeout: Option<Duration>,
+    alloc: bool,
+    variants: bool,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -18,6 +51,22 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
+    if store {
+        cmd_args.push("--store".to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+    cmd_args.extend(part_args(part));
+    cmd_args.extend(timeout_args(timeout));
+
+    if alloc {
+        cmd_args.push("--alloc".to_string());
+    }
+
+    if variants {
+        cmd_args.push("--variants".to_string());
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
@@ -30,5 +79,425 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // NOTE: the solution exits with a non-zero status code if a variant disagrees with its part.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Reruns a solution whenever its module, the shared library or its inputs change.
+/// Answers are compared against the previous run.
+#[allow(clippy::too_many_arguments)]
+pub fn watch(
+    year: Year,
+    day: Day,
+    part: Option<u8>,
+    release: bool,
+    test: bool,
+    input: &InputSource,
+    timeout: Option<Duration>,
+    alloc: bool,
+) {
+    let mut watcher = Watcher::new(year, day);
+    let mut previous: Option<Vec<PartReport>> = None;
+
+    loop {
+        if test {
+            run_tests(year, day, release);
+        }
+
+        let reports = run_reports(year, day, part, release, input, timeout, alloc);
+
+        if let Some(previous) = &previous {
+            print_answer_diff(previous, &reports);
+        }
+        previous = Some(reports);
+
+        println!("\n{ANSI_ITALIC}Watching for changes...{ANSI_RESET}");
+
+        let changed = watcher.wait();
+        println!();
+        for path in changed {
+            println!("Change detected in \"{}\", rerunning...", path.display());
+        }
+        println!();
+    }
+}
+
+/// Mirrors the selected part to a solution binary as `--part <part>`.
+fn part_args(part: Option<u8>) -> Vec<String> {
+    match part {
+        Some(part) => vec!["--part".into(), part.to_string()],
+        None => vec![],
+    }
+}
+
+/// Mirrors the timeout to a solution binary as `--timeout <secs>`.
+fn timeout_args(timeout: Option<Duration>) -> Vec<String> {
+    match timeout {
+        Some(timeout) => vec!["--timeout".into(), timeout.as_secs_f64().to_string()],
+        None => vec![],
+    }
+}
+
+fn cargo_args(command: &str, year: Year, day: Day, release: bool) -> Vec<String> {
+    let mut args = vec![
+        command.to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
+    if release {
+        args.push("--release".to_string());
+    }
+    args
+}
+
+fn run_tests(year: Year, day: Day, release: bool) {
+    let status = Command::new("cargo")
+        .args(cargo_args("test", year, day, release))
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    if let Err(e) = status {
+        eprintln!("Failed to run tests: {e}");
+    }
+}
+
+/// Runs the solution in `--format json` mode and returns the reports of the parts that ran.
+/// Other output of the solution is passed through.
+fn run_reports(
+    year: Year,
+    day: Day,
+    part: Option<u8>,
+    release: bool,
+    input: &InputSource,
+    timeout: Option<Duration>,
+    alloc: bool,
+) -> Vec<PartReport> {
+    let mut args = cargo_args("run", year, day, release);
+    args.extend(["--".to_string(), "--format".to_string(), "json".to_string()]);
+    args.extend(input.to_args());
+    args.extend(part_args(part));
+    args.extend(timeout_args(timeout));
+    if alloc {
+        args.push("--alloc".to_string());
+    }
+
+    let mut child = match Command::new("cargo")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e}");
+            return vec![];
+        }
+    };
+
+    let mut reports = vec![];
+
+    // NOTE: stdout is piped above.
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    for line in stdout.lines().map_while(Result::ok) {
+        match PartReport::from_json_line(&line) {
+            Some(Ok(report)) => {
+                print_report(&report);
+                reports.push(report);
+            }
+            Some(Err(e)) => eprintln!("Failed to parse report: {e}"),
+            None => println!("{line}"),
+        }
+    }
+
+    let _ = child.wait();
+    reports
+}
+
+/// Returns the parts whose answer differs from the previous run, as `(part, previous, current)`.
+fn answer_diff(
+    previous: &[PartReport],
+    current: &[PartReport],
+) -> Vec<(u8, Option<String>, Option<String>)> {
+    let answer = |reports: &[PartReport], part: u8| {
+        reports
+            .iter()
+            .find(|r| r.part == part && r.variant.is_none())
+            .and_then(|r| r.answer.clone())
+    };
+
+    [1, 2]
+        .into_iter()
+        .map(|part| (part, answer(previous, part), answer(current, part)))
+        .filter(|(_, a, b)| a != b)
+        .collect()
+}
+
+fn print_answer_diff(previous: &[PartReport], current: &[PartReport]) {
+    let diff = answer_diff(previous, current);
+
+    if diff.is_empty() {
+        println!("\n{ANSI_ITALIC}Answers unchanged.{ANSI_RESET}");
+        return;
+    }
+
+    let format = |answer: &Option<String>| answer.as_deref().unwrap_or("✖").to_string();
+
+    println!();
+    for (part, a, b) in diff {
+        println!(
+            "{ANSI_BOLD}Part {part}:{ANSI_RESET} {} → {ANSI_BOLD}{}{ANSI_RESET}",
+            format(&a),
+            format(&b)
+        );
+    }
+}
+
+/// Runs both parts of a solution against every example file of a day and prints a table of the answers.
+/// Answers are checked against the examples ledger if answers were stored for a file.
+/// With `store`, the answers are recorded in the ledger instead.
+/// Only `part` runs if it is set, the stored answer of the other part is kept.
+pub fn examples(year: Year, day: Day, part: Option<u8>, store: bool, timeout: Option<Duration>) {
+    let Some(solution) = registry::get(year, day) else {
+        eprintln!("Day {day} of {year} is not registered in \"src/solutions.rs\".");
+        process::exit(1);
+    };
+
+    let names = example_names(year, day);
+    if names.is_empty() {
+        eprintln!("Day {day} has no non-empty example files.");
+        process::exit(1);
+    }
+
+    let mut rows = vec![];
+    let mut failed = false;
+
+    for name in names {
+        let suffix = name.split_once('-').and_then(|(_, n)| n.parse().ok());
+        let input = match suffix {
+            Some(n) => try_read_file_part("examples", year, day, n),
+            None => try_read_file("examples", year, day),
+        };
+
+        let input: Arc<str> = match input {
+            Ok(input) => input.into(),
+            Err(e) => {
+                rows.push((name, [format!("✖ {e}"), String::new()]));
+                continue;
+            }
+        };
+
+        let is_selected = |x: u8| part.is_none_or(|part| part == x);
+
+        let answers = [1, 2].map(
+            |part| match solution.part(part).filter(|_| is_selected(part)) {
+                // NOTE: panics are reported in the table, `run_part_once` keeps the default message from garbling it.
+                Some(func) => {
+                    match run_part_once(move |input: Arc<str>| func(&input), input.clone(), timeout)
+                    {
+                        Ok(answer) => ExampleAnswer::Solved(answer),
+                        Err(status) => ExampleAnswer::Failed(status),
+                    }
+                }
+                None => ExampleAnswer::Missing,
+            },
+        );
+
+        if store {
+            let previous = if part.is_some() {
+                Answers::read_example_from_file(year, &name).ok().flatten()
+            } else {
+                None
+            };
+            let [part_1, part_2] = [1, 2].map(|part| {
+                if is_selected(part) {
+                    answers[usize::from(part) - 1].clone().into_answer()
+                } else {
+                    previous.as_ref().and_then(|x| x.get(part).cloned())
+                }
+            });
+            if let Err(e) = (Answers { part_1, part_2 }).store_example_file(year, &name) {
+                eprintln!("Failed to store answers for example {name}: {e}");
+            }
+        }
+
+        let ledger = if store {
+            None
+        } else {
+            Answers::read_example_from_file(year, &name).unwrap_or_else(|e| {
+                eprintln!("Failed to read stored answers for example {name}: {e}");
+                None
+            })
+        };
+
+        let cells = [1, 2].map(|part| {
+            let answer = answers[usize::from(part) - 1].clone();
+            let (cell, ok) = format_cell(answer, ledger.as_ref(), part);
+            failed |= !ok;
+            cell
+        });
+
+        rows.push((name, cells));
+    }
+
+    print_table(&rows);
+
+    if store {
+        println!("\nStored answers in the examples ledger.");
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Answer of a part for an example file.
+#[derive(Clone)]
+enum ExampleAnswer {
+    Solved(Option<String>),
+    /// The part panicked or timed out.
+    Failed(PartStatus),
+    /// The solution does not implement the part.
+    Missing,
+}
+
+impl ExampleAnswer {
+    fn into_answer(self) -> Option<String> {
+        match self {
+            ExampleAnswer::Solved(answer) => answer,
+            _ => None,
+        }
+    }
+}
+
+/// Formats a table cell for an answer, checked against the ledger if there is one.
+/// Returns the cell and whether the answer passed.
+fn format_cell(answer: ExampleAnswer, ledger: Option<&Answers>, part: u8) -> (String, bool) {
+    let answer = match answer {
+        ExampleAnswer::Missing => return ("-".into(), true),
+        ExampleAnswer::Failed(status) => {
+            let expected = ledger.and_then(|l| l.get(part));
+            let cell = match status {
+                PartStatus::TimedOut => "✖ timed out",
+                _ => "✖ panicked",
+            };
+            return (cell.into(), expected.is_none());
+        }
+        ExampleAnswer::Solved(answer) => answer,
+    };
+
+    // NOTE: multi-line answers are shortened to their first line to keep the table intact.
+    let answer_str = match answer.as_deref() {
+        Some(x) if x.contains('\n') => format!("{}…", x.lines().next().unwrap_or_default()),
+        Some(x) => x.to_string(),
+        None => "✖".into(),
+    };
+
+    match ledger.map(|l| l.check(part, answer.as_deref())) {
+        Some(AnswerStatus::Matching) => (format!("✔ {answer_str}"), true),
+        Some(AnswerStatus::Mismatching { expected }) => {
+            (format!("✘ {answer_str} (expected {expected})"), false)
+        }
+        Some(AnswerStatus::Missing) | None => (answer_str, true),
+    }
+}
+
+fn print_table(rows: &[(String, [String; 2])]) {
+    let width =
+        |cells: &mut dyn Iterator<Item = &str>| cells.map(|x| x.chars().count()).max().unwrap_or(0);
+
+    let widths = [
+        width(&mut rows.iter().map(|r| r.0.as_str()).chain(["Example"])),
+        width(&mut rows.iter().map(|r| r.1[0].as_str()).chain(["Part 1"])),
+    ];
+
+    println!(
+        "{ANSI_BOLD}{:<w0$}  {:<w1$}  Part 2{ANSI_RESET}",
+        "Example",
+        "Part 1",
+        w0 = widths[0],
+        w1 = widths[1]
+    );
+
+    for (name, [part_1, part_2]) in rows {
+        println!(
+            "{name:<w0$}  {part_1:<w1$}  {part_2}",
+            w0 = widths[0],
+            w1 = widths[1]
+        );
+    }
+}
+
+/// Returns the names of the non-empty example files of a day, e.g. `12-1` for `data/2024/examples/12-1.txt`.
+/// The file without a suffix comes first, the others are ordered by their suffix.
+fn example_names(year: Year, day: Day) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(data_dir(year).join("examples")) else {
+        return vec![];
+    };
+
+    let mut names: Vec<(u8, String)> = entries
+        .flatten()
+        .filter(|entry| entry.metadata().is_ok_and(|m| m.len() > 0))
+        .filter_map(|entry| {
+            let name = entry
+                .file_name()
+                .to_str()?
+                .strip_suffix(".txt")?
+                .to_string();
+            let order = example_order(&name, day)?;
+            Some((order, name))
+        })
+        .collect();
+
+    names.sort_unstable();
+    names.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Returns the sort key of an example file name if it belongs to a day, e.g. `0` for `12` and `3` for `12-3`.
+fn example_order(name: &str, day: Day) -> Option<u8> {
+    let rest = name.strip_prefix(&day.to_string())?;
+    if rest.is_empty() {
+        Some(0)
+    } else {
+        rest.strip_prefix('-')?.parse().ok()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_diff, example_order};
+    use crate::{
+        day,
+        template::{report::PartReport, runner::BenchStats},
+    };
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        let stats = BenchStats::from_samples(&[std::time::Duration::from_micros(1)]).unwrap();
+        PartReport::new(day!(1), part, answer, stats)
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = vec![report(1, Some("11")), report(2, None)];
+        let current = vec![report(1, Some("11")), report(2, Some("31"))];
+
+        assert_eq!(
+            answer_diff(&previous, &current),
+            vec![(2, None, Some("31".to_string()))]
+        );
+        assert!(answer_diff(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn orders_example_files() {
+        assert_eq!(example_order("12", day!(12)), Some(0));
+        assert_eq!(example_order("12-3", day!(12)), Some(3));
+        assert_eq!(example_order("12-x", day!(12)), None);
+        assert_eq!(example_order("01", day!(12)), None);
+    }
 }
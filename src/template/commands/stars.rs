@@ -0,0 +1,26 @@
+use std::process;
+
+use crate::template::aoc_client::AocClient;
+use crate::template::readme::{update_stars, AnswerLedger};
+use crate::template::Year;
+
+/// Regenerates the stars table of the readme from the answer ledger, or from the calendar on the website.
+pub fn handle(year: Year, aoc: bool) {
+    let result = if aoc {
+        let client = AocClient::from_env(year).unwrap_or_else(|e| {
+            eprintln!("failed to create Advent of Code client: {e}");
+            process::exit(1);
+        });
+        update_stars(year, &client)
+    } else {
+        update_stars(year, &AnswerLedger)
+    };
+
+    match result {
+        Ok(stars) => println!("Updated the readme with {stars} ⭐ for {year}."),
+        Err(e) => {
+            eprintln!("Failed to update the stars table: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,34 +1,85 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, path::Path, process, time::Duration};
 
+use crate::template::compare::{environment_warning, find_baseline, print_comparison, Comparison};
+use crate::template::environment::Environment;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
-
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
-
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
+use crate::template::runner::BenchConfig;
+use crate::template::timings::{TimingRun, Timings};
+use crate::template::{all_days, bench_history, readme_benchmarks, Day, MergePolicy, Year};
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    days: Option<&HashSet<Day>>,
+    part: Option<u8>,
+    run_all: bool,
+    store: bool,
+    bench: &BenchConfig,
+    compare: Option<&Comparison>,
+    timeout: Option<Duration>,
+    variants: bool,
+    merge: MergePolicy,
+    report: Option<&Path>,
+) {
+    let stored_timings = Timings::read_from_file(year);
+
+    let days_to_run: HashSet<Day> = days.cloned().unwrap_or_else(|| {
+        // comparisons need fresh timings of every day.
+        if run_all || compare.is_some() {
+            all_days().collect()
+        } else {
+            // when the `--all` flag is not set, filter out days that are fully benched.
+            all_days()
+                .filter(|day| match part {
+                    Some(part) => !stored_timings.is_part_benched(*day, part),
+                    None => !stored_timings.is_day_complete(*day),
+                })
+                .collect()
+        }
+    });
+
+    let (timings, variants_agree) =
+        run_multi(year, &days_to_run, part, Some(bench), timeout, variants);
+    let timings = timings.unwrap();
+    let environment = Environment::current();
+
+    if let Some(comparison) = compare {
+        match find_baseline(&stored_timings, comparison.reference.as_deref()) {
+            Ok(baseline) => {
+                print_comparison(&baseline, &timings.data, &environment, comparison.threshold);
             }
-        },
-        |day| HashSet::from([day]),
-    );
+            Err(e) => {
+                eprintln!("Failed to compare benchmarks: {e}");
+            }
+        }
+    }
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let mut merged_timings = stored_timings.merge(&timings, merge, year);
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        // NOTE: days that did not run keep their stored timings, which may come from another machine or toolchain.
+        let keeps_stored = merged_timings
+            .data
+            .iter()
+            .any(|stored| timings.data.iter().all(|t| t.day != stored.day));
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        if let Some(warning) = stored_timings
+            .history
+            .last()
+            .filter(|_| keeps_stored)
+            .and_then(|previous| environment_warning(previous, &environment))
+        {
+            println!("{warning}");
+            println!("The readme mixes timings of both environments, run `cargo time --all --store` to replace them.");
+        }
+
+        merged_timings
+            .history
+            .push(TimingRun::new(timings.data, environment));
+        merged_timings.store_file(year).unwrap();
+
+        match readme_benchmarks::update(year, merged_timings.clone()) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +88,23 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    // NOTE: without `--store`, the report contains the timings of this run, but not in the history.
+    if let Some(dir) = report {
+        println!();
+        match bench_history::write(year, &merged_timings, dir) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Wrote \"{}\".", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to write benchmark report: {e}");
+            }
+        }
+    }
+
+    if !variants_agree {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,304 @@
+/// Module that compares benchmark times between two runs.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::environment::Environment;
+use crate::template::timings::{format_nanos, Timing, TimingRun, Timings};
+use crate::template::{git, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Options of `cargo time --compare[=ref]`.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    /// A git ref to compare against. The latest stored run is used if not set.
+    pub reference: Option<String>,
+    /// Slowdowns above this percentage are flagged as regressions.
+    pub threshold: f64,
+}
+
+/// The change of a part's benchmark time between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl PartDelta {
+    /// Relative change in percent, positive values are slowdowns.
+    pub fn percent(&self) -> f64 {
+        (self.current_nanos - self.baseline_nanos) / self.baseline_nanos * 100_f64
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/// Computes deltas for all parts that have a time in both `baseline` and `current`.
+pub fn compute_deltas(baseline: &[Timing], current: &[Timing]) -> Vec<PartDelta> {
+    let mut deltas = vec![];
+
+    for timing in current {
+        let Some(base) = baseline.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for part in [1, 2] {
+            if let (Some(baseline_nanos), Some(current_nanos)) =
+                (base.part_nanos(part), timing.part_nanos(part))
+            {
+                if baseline_nanos > 0_f64 {
+                    deltas.push(PartDelta {
+                        day: timing.day,
+                        part,
+                        baseline_nanos,
+                        current_nanos,
+                    });
+                }
+            }
+        }
+    }
+
+    deltas
+}
+
+/// Selects the stored run to compare against.
+/// Falls back to the latest stored timings if no reference is given and there is no history yet.
+pub fn find_baseline(stored: &Timings, reference: Option<&str>) -> Result<TimingRun, String> {
+    match reference {
+        Some(reference) => {
+            let commit = git::resolve(reference).unwrap_or_else(|| reference.to_string());
+            stored
+                .find_run(&commit)
+                .cloned()
+                .ok_or_else(|| format!("no stored run found for `{reference}`."))
+        }
+        None => match stored.history.last() {
+            Some(run) => Ok(run.clone()),
+            None if !stored.data.is_empty() => Ok(TimingRun {
+                timestamp: 0,
+                commit: None,
+                environment: None,
+                data: stored.data.clone(),
+            }),
+            None => Err("no stored timings to compare against.".into()),
+        },
+    }
+}
+
+/// Describes how the environment of the current run differs from the one of `baseline`.
+/// Returns `None` if both are equal or the environment of `baseline` was not recorded.
+pub fn environment_warning(baseline: &TimingRun, current: &Environment) -> Option<String> {
+    let differences = baseline.environment.as_ref()?.differences(current);
+
+    if differences.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "{ANSI_BOLD}⚠ different environment{ANSI_RESET}: {}",
+            differences.join(", ")
+        ))
+    }
+}
+
+/// Prints a table of per-part deltas. Returns the number of flagged regressions.
+/// Warns if the baseline was recorded in a different environment than `environment`.
+pub fn print_comparison(
+    baseline: &TimingRun,
+    current: &[Timing],
+    environment: &Environment,
+    threshold: f64,
+) -> usize {
+    let deltas = compute_deltas(&baseline.data, current);
+
+    let commit = baseline
+        .commit
+        .as_deref()
+        .map_or("unknown commit", git::short_hash);
+    println!(
+        "\n{ANSI_BOLD}Comparison{ANSI_RESET} against {commit} {ANSI_ITALIC}({}){ANSI_RESET}",
+        format_age(baseline.timestamp)
+    );
+
+    if let Some(warning) = environment_warning(baseline, environment) {
+        println!("{warning}");
+    }
+
+    if deltas.is_empty() {
+        println!("No parts to compare.");
+        return 0;
+    }
+
+    let mut regressions = 0;
+
+    for delta in &deltas {
+        let flag = if delta.is_regression(threshold) {
+            regressions += 1;
+            format!(" {ANSI_BOLD}⚠ regression{ANSI_RESET}")
+        } else {
+            String::new()
+        };
+
+        println!(
+            "Day {} Part {}: {} → {} ({:+.1}%){flag}",
+            delta.day,
+            delta.part,
+            format_nanos(delta.baseline_nanos),
+            format_nanos(delta.current_nanos),
+            delta.percent()
+        );
+    }
+
+    println!(
+        "{regressions} regression(s) above {threshold}% in {} compared part(s).",
+        deltas.len()
+    );
+
+    regressions
+}
+
+fn format_age(timestamp: u64) -> String {
+    if timestamp == 0 {
+        return "unknown date".into();
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    match now.saturating_sub(timestamp) {
+        s if s < 60 * 60 => format!("{}m ago", s / 60),
+        s if s < 60 * 60 * 24 => format!("{}h ago", s / (60 * 60)),
+        s => format!("{}d ago", s / (60 * 60 * 24)),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compute_deltas, environment_warning, find_baseline, PartDelta};
+    use crate::{
+        day,
+        template::{
+            environment::Environment,
+            report::PartStatus,
+            timings::{PartTiming, Timing, TimingRun, Timings},
+        },
+    };
+
+    fn timing(day: crate::template::Day, part_1: Option<f64>, part_2: Option<f64>) -> Timing {
+        let parts = [(1, part_1), (2, part_2)]
+            .into_iter()
+            .filter_map(|(part, nanos)| {
+                Some(PartTiming {
+                    part,
+                    nanos: nanos?,
+                    samples: Some(10),
+                    status: PartStatus::Solved,
+                    alloc: None,
+                })
+            })
+            .collect();
+
+        Timing { day, parts }
+    }
+
+    fn run(commit: &str, data: Vec<Timing>) -> TimingRun {
+        TimingRun {
+            timestamp: 1,
+            commit: Some(commit.into()),
+            environment: None,
+            data,
+        }
+    }
+
+    #[test]
+    fn computes_deltas_for_shared_parts() {
+        let baseline = vec![
+            timing(day!(6), Some(1e+6), Some(589.2e+6)),
+            timing(day!(9), Some(774.1e+3), None),
+        ];
+        let current = vec![
+            timing(day!(6), Some(1.1e+6), Some(294.6e+6)),
+            timing(day!(9), Some(774.1e+3), Some(296.1e+6)),
+            timing(day!(10), Some(1e+6), None),
+        ];
+
+        let deltas = compute_deltas(&baseline, &current);
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(
+            deltas[1],
+            PartDelta {
+                day: day!(6),
+                part: 2,
+                baseline_nanos: 589_200_000_f64,
+                current_nanos: 294_600_000_f64
+            }
+        );
+        assert!((deltas[0].percent() - 10_f64).abs() < 1e-6);
+        assert!(deltas[0].is_regression(5_f64));
+        assert!(!deltas[0].is_regression(10.5));
+        assert!((deltas[1].percent() + 50_f64).abs() < 1e-6);
+        assert_eq!(deltas[2].percent(), 0_f64);
+    }
+
+    #[test]
+    fn finds_baseline_by_commit() {
+        let stored = Timings {
+            data: vec![],
+            history: vec![
+                run("aaaaaaa1111", vec![timing(day!(1), Some(1e+6), None)]),
+                run("bbbbbbb2222", vec![timing(day!(1), Some(2e+6), None)]),
+                run("aaaaaaa1111", vec![timing(day!(1), Some(3e+6), None)]),
+            ],
+        };
+
+        let baseline = find_baseline(&stored, Some("bbbbbbb")).unwrap();
+        assert_eq!(baseline.data[0].part_nanos(1), Some(2e+6));
+
+        let baseline = find_baseline(&stored, Some("aaaaaaa1111")).unwrap();
+        assert_eq!(baseline.data[0].part_nanos(1), Some(3e+6));
+
+        let baseline = find_baseline(&stored, None).unwrap();
+        assert_eq!(baseline.data[0].part_nanos(1), Some(3e+6));
+
+        assert!(find_baseline(&stored, Some("ccccccc")).is_err());
+    }
+
+    #[test]
+    fn falls_back_to_stored_timings() {
+        let stored = Timings {
+            data: vec![timing(day!(1), Some(1e+6), None)],
+            history: vec![],
+        };
+        let baseline = find_baseline(&stored, None).unwrap();
+        assert_eq!(baseline.commit, None);
+        assert_eq!(baseline.data.len(), 1);
+
+        assert!(find_baseline(&Timings::default(), None).is_err());
+    }
+
+    #[test]
+    fn warns_about_different_environments() {
+        let environment = Environment {
+            cpu: Some("Apple M1".into()),
+            cores: Some(8),
+            rustc: Some("1.83.0".into()),
+            profile: "release".into(),
+            features: vec![],
+        };
+
+        let mut baseline = run("aaaaaaa1111", vec![]);
+        assert_eq!(environment_warning(&baseline, &environment), None);
+
+        baseline.environment = Some(environment.clone());
+        assert_eq!(environment_warning(&baseline, &environment), None);
+
+        let current = Environment {
+            profile: "debug".into(),
+            ..environment
+        };
+        assert!(environment_warning(&baseline, &current)
+            .unwrap()
+            .ends_with("profile release → debug"));
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -132,6 +133,50 @@ impl Iterator for AllDays {
     }
 }
 
+/// Parses a day expression into the selected days, e.g. `6`, `1-10`, `3,7,12` or `!6`.
+/// Terms are separated by commas. Terms starting with `!` exclude days, if there are only exclusions, they apply to all days.
+pub fn parse_days(s: &str) -> Result<HashSet<Day>, String> {
+    let parse_day = |x: &str| {
+        x.trim()
+            .parse::<Day>()
+            .map_err(|e| format!("invalid day `{}` in `{s}`, {e}.", x.trim()))
+    };
+
+    let mut included: Option<HashSet<Day>> = None;
+    let mut excluded = HashSet::new();
+
+    for term in s.split(',').map(str::trim) {
+        let (exclude, range) = match term.strip_prefix('!') {
+            Some(range) => (true, range),
+            None => (false, term),
+        };
+
+        let days: Vec<Day> = match range.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse_day(from)?, parse_day(to)?);
+                if from > to {
+                    return Err(format!(
+                        "invalid range `{range}` in `{s}`, expecting the first day to come first."
+                    ));
+                }
+                all_days()
+                    .filter(|day| *day >= from && *day <= to)
+                    .collect()
+            }
+            None => vec![parse_day(range)?],
+        };
+
+        if exclude {
+            excluded.extend(days);
+        } else {
+            included.get_or_insert_with(HashSet::new).extend(days);
+        }
+    }
+
+    let included = included.unwrap_or_else(|| all_days().collect());
+    Ok(included.difference(&excluded).copied().collect())
+}
+
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
@@ -154,7 +199,33 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use std::collections::HashSet;
+
+    use super::{all_days, parse_days, Day};
+
+    fn days(days: &[u8]) -> HashSet<Day> {
+        days.iter().map(|x| Day(*x)).collect()
+    }
+
+    #[test]
+    fn parses_day_expressions() {
+        assert_eq!(parse_days("6").unwrap(), days(&[6]));
+        assert_eq!(parse_days("1-4").unwrap(), days(&[1, 2, 3, 4]));
+        assert_eq!(parse_days("3, 7,12").unwrap(), days(&[3, 7, 12]));
+        assert_eq!(parse_days("1-4,!2").unwrap(), days(&[1, 3, 4]));
+        assert_eq!(parse_days("!6").unwrap().len(), 24);
+        assert!(!parse_days("!6").unwrap().contains(&Day(6)));
+        assert_eq!(parse_days("!2-25").unwrap(), days(&[1]));
+    }
+
+    #[test]
+    fn errors_for_invalid_day_expressions() {
+        assert!(parse_days("").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("10-1").is_err());
+        assert!(parse_days("1-").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
 
     #[test]
     fn all_days_iterator() {
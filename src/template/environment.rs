@@ -0,0 +1,278 @@
+/// Fingerprint of the machine and toolchain that produced a benchmark run.
+/// Runs from different environments are not comparable, e.g. a laptop on battery and a desktop.
+use std::{collections::HashMap, fs, process::Command, thread};
+
+use tinyjson::JsonValue;
+
+/// The environment of a stored run. The git commit is stored with the run itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Environment {
+    /// CPU model, read from `/proc/cpuinfo`. Not known on other platforms.
+    pub cpu: Option<String>,
+    /// Number of cores available to the process.
+    pub cores: Option<usize>,
+    /// Output of `rustc --version`, without the `rustc` prefix.
+    pub rustc: Option<String>,
+    /// `release` or `debug`.
+    pub profile: String,
+    /// Enabled cargo features of the template.
+    pub features: Vec<String>,
+}
+
+impl Environment {
+    /// Fingerprints the current process.
+    pub fn current() -> Self {
+        let features = [
+            ("dhat-heap", cfg!(feature = "dhat-heap")),
+            ("today", cfg!(feature = "today")),
+            ("test_lib", cfg!(feature = "test_lib")),
+        ]
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+        Self {
+            cpu: fs::read_to_string("/proc/cpuinfo")
+                .ok()
+                .and_then(|cpuinfo| parse_cpu_model(&cpuinfo)),
+            cores: thread::available_parallelism().ok().map(Into::into),
+            rustc: rustc_version(),
+            profile: if cfg!(debug_assertions) {
+                "debug".into()
+            } else {
+                "release".into()
+            },
+            features,
+        }
+    }
+
+    /// Describes how `other` differs from `self`, e.g. `rustc 1.82.0 → 1.83.0`. Empty if both are equal.
+    pub fn differences(&self, other: &Self) -> Vec<String> {
+        fn describe<T: ToString>(value: Option<&T>) -> String {
+            value.map_or_else(|| "unknown".into(), ToString::to_string)
+        }
+
+        let mut differences = vec![];
+
+        if self.cpu != other.cpu {
+            differences.push(format!(
+                "cpu {} → {}",
+                describe(self.cpu.as_ref()),
+                describe(other.cpu.as_ref())
+            ));
+        }
+        if self.cores != other.cores {
+            differences.push(format!(
+                "cores {} → {}",
+                describe(self.cores.as_ref()),
+                describe(other.cores.as_ref())
+            ));
+        }
+        if self.rustc != other.rustc {
+            differences.push(format!(
+                "rustc {} → {}",
+                describe(self.rustc.as_ref()),
+                describe(other.rustc.as_ref())
+            ));
+        }
+        if self.profile != other.profile {
+            differences.push(format!("profile {} → {}", self.profile, other.profile));
+        }
+        if self.features != other.features {
+            let features = |x: &[String]| {
+                if x.is_empty() {
+                    "none".into()
+                } else {
+                    x.join(",")
+                }
+            };
+            differences.push(format!(
+                "features {} → {}",
+                features(&self.features),
+                features(&other.features)
+            ));
+        }
+
+        differences
+    }
+}
+
+/// Reads the model of the first processor from the contents of `/proc/cpuinfo`.
+fn parse_cpu_model(cpuinfo: &str) -> Option<String> {
+    cpuinfo
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "model name")
+        .map(|(_, value)| value.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|model| !model.is_empty())
+}
+
+fn rustc_version() -> Option<String> {
+    let output = Command::new("rustc").arg("--version").output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let version = String::from_utf8(output.stdout).ok()?;
+    let version = version.trim();
+    Some(
+        version
+            .strip_prefix("rustc ")
+            .unwrap_or(version)
+            .to_string(),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn optional_string(value: Option<&String>) -> JsonValue {
+    match value {
+        Some(x) => JsonValue::String(x.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+impl From<&Environment> for JsonValue {
+    fn from(value: &Environment) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("cpu".into(), optional_string(value.cpu.as_ref()));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "cores".into(),
+            match value.cores {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert("rustc".into(), optional_string(value.rustc.as_ref()));
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+        map.insert(
+            "features".into(),
+            JsonValue::Array(
+                value
+                    .features
+                    .iter()
+                    .map(|x| JsonValue::String(x.clone()))
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Environment {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected environment to be a JSON object.")?;
+
+        let get_string = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected environment.{key} to be null or string."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let cores = match json.get("cores") {
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|x| *x as usize)
+                    .ok_or("Expected environment.cores to be null or a number.")?,
+            ),
+            None => return Err("Expected environment.cores to be null or a number.".into()),
+        };
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected environment.profile to be a string.")?;
+
+        let features = json
+            .get("features")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .and_then(|x| {
+                x.iter()
+                    .map(|feature| feature.get::<String>().cloned())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or("Expected environment.features to be an array of strings.")?;
+
+        Ok(Environment {
+            cpu: get_string("cpu")?.cloned(),
+            cores,
+            rustc: get_string("rustc")?.cloned(),
+            profile: profile.clone(),
+            features,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{parse_cpu_model, Environment};
+
+    fn environment() -> Environment {
+        Environment {
+            cpu: Some("AMD Ryzen 7 5800X 8-Core Processor".into()),
+            cores: Some(16),
+            rustc: Some("1.83.0 (90b35a623 2024-11-26)".into()),
+            profile: "release".into(),
+            features: vec![],
+        }
+    }
+
+    #[test]
+    fn parses_cpu_model() {
+        let cpuinfo = "processor\t: 0\nvendor_id\t: AuthenticAMD\nmodel name\t: AMD Ryzen 7 5800X  8-Core Processor\nflags\t\t: fpu vme\n\nprocessor\t: 1\nmodel name\t: AMD Ryzen 7 5800X  8-Core Processor\n";
+        assert_eq!(
+            parse_cpu_model(cpuinfo),
+            Some("AMD Ryzen 7 5800X 8-Core Processor".into())
+        );
+        assert_eq!(parse_cpu_model("processor\t: 0\nBogoMIPS\t: 48.00\n"), None);
+    }
+
+    #[test]
+    fn describes_differences() {
+        let baseline = environment();
+        assert!(baseline.differences(&baseline).is_empty());
+
+        let current = Environment {
+            rustc: Some("1.84.0 (9fc6b4312 2025-01-07)".into()),
+            profile: "debug".into(),
+            features: vec!["dhat-heap".into()],
+            ..environment()
+        };
+        assert_eq!(
+            baseline.differences(&current),
+            [
+                "rustc 1.83.0 (90b35a623 2024-11-26) → 1.84.0 (9fc6b4312 2025-01-07)",
+                "profile release → debug",
+                "features none → dhat-heap",
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let environment = Environment {
+            cpu: None,
+            features: vec!["today".into()],
+            ..environment()
+        };
+        let json = JsonValue::from(&environment);
+        assert_eq!(Environment::try_from(&json).unwrap(), environment);
+    }
+}
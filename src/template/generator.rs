@@ -0,0 +1,219 @@
+//! Generators for puzzle inputs. Generated inputs have the structure of real puzzle inputs,
+//! so solutions can be tested and benched without the private inputs in `data/<year>/inputs`.
+//! The generators of a year are registered in `./src/generators.rs`.
+use std::{fmt::Display, ops::Range};
+
+use crate::template::answers::{AnswerStatus, Answers};
+use crate::template::{registry::Solution, Day, Year};
+
+/// Seeds of the inputs that [`check_solution`] generates, as some answers only differ for some inputs.
+const CHECK_SEEDS: [u64; 3] = [1, 2, 3];
+
+/// Creates puzzle inputs of a day from a seed and a size.
+///
+/// The meaning of the size depends on the day, e.g. the side length of a grid or the number of lines.
+/// The same seed and size always produce the same input.
+pub trait InputGenerator: Sync {
+    /// The size of real puzzle inputs, used if no size is passed to `cargo generate`.
+    fn default_size(&self) -> usize;
+
+    /// A small size that keeps the tests of the solutions fast in debug builds.
+    fn test_size(&self) -> usize;
+
+    /// Writes an input of the given size, drawing all random choices from `rng`.
+    fn generate_with(&self, rng: &mut Rng, size: usize) -> GeneratedInput;
+
+    /// Creates the input for a seed and a size.
+    fn generate(&self, seed: u64, size: usize) -> GeneratedInput {
+        self.generate_with(&mut Rng::new(seed), size)
+    }
+}
+
+/// A generated input, with the answers the generator knows for it.
+pub struct GeneratedInput {
+    pub input: String,
+    /// Answers known from the construction of the input or computed independently of the solution.
+    /// Parts the generator can't answer are left empty.
+    pub answers: Answers,
+}
+
+impl GeneratedInput {
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            answers: Answers::default(),
+        }
+    }
+
+    /// Adds the known answer of a part.
+    #[must_use]
+    pub fn with_answer(mut self, part: u8, answer: impl Display) -> Self {
+        self.answers.set(part, answer.to_string());
+        self
+    }
+}
+
+/// The generator of a single day, as registered in `./src/generators.rs`.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub year: Year,
+    pub day: Day,
+    pub generator: &'static dyn InputGenerator,
+}
+
+/// All registered generators, ordered by year and day.
+pub fn generators() -> &'static [Generator] {
+    crate::generators::GENERATORS
+}
+
+/// Returns the registered generator for a day of a year, if there is one.
+pub fn get(year: Year, day: Day) -> Option<&'static dyn InputGenerator> {
+    generators()
+        .iter()
+        .find(|g| g.year == year && g.day == day)
+        .map(|g| g.generator)
+}
+
+/// Helper function that generates the input of a day, e.g. to test a solution against generated inputs.
+/// Panics if there is no generator for the day.
+#[must_use]
+pub fn generate(year: Year, day: Day, seed: u64, size: usize) -> GeneratedInput {
+    get(year, day)
+        .expect("no generator registered for day")
+        .generate(seed, size)
+}
+
+/// Runs the parts and variants of a solution against generated inputs of its day, at the test size of the generator.
+/// Panics if an answer differs from the one known by the generator, or a variant differs from its part.
+pub fn check_solution(solution: &Solution) {
+    let (year, day) = (solution.year, solution.day);
+    let generator = get(year, day).expect("no generator registered for day");
+
+    for seed in CHECK_SEEDS {
+        let generated = generator.generate(seed, generator.test_size());
+
+        for (part, func) in solution.parts {
+            let answer = func(&generated.input);
+
+            if let AnswerStatus::Mismatching { expected } =
+                generated.answers.check(*part, answer.as_deref())
+            {
+                panic!("day {day} part {part}, seed {seed}: got {answer:?}, expected {expected}");
+            }
+
+            for (name, variant) in solution.variants(*part) {
+                assert_eq!(
+                    variant(&generated.input),
+                    answer,
+                    "day {day} {name}, seed {seed}: differs from part {part}"
+                );
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A small, seedable pseudo-random number generator (SplitMix64).
+/// Generated inputs only depend on the seed, not on the platform or a dependency version.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `range`. Panics if the range is empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "cannot pick from an empty range");
+        let span = range.end - range.start;
+        // NOTE: the modulo bias is negligible for the small ranges used by generators.
+        range.start + self.next_u64() % span
+    }
+
+    /// Returns an index below `len`. Panics if `len` is zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..len as u64) as usize
+    }
+
+    /// Returns `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Returns a random element of a non-empty slice.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles a slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{generators, Rng};
+    use crate::template::all_days;
+
+    #[test]
+    fn rng_is_deterministic() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn rng_stays_in_range() {
+        let mut rng = Rng::new(1);
+        assert!((0..1000).all(|_| (3..9).contains(&rng.range(3..9))));
+        assert!((0..1000).all(|_| rng.index(5) < 5));
+    }
+
+    #[test]
+    fn rng_shuffles_permutations() {
+        let mut items: Vec<u32> = (0..50).collect();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn generators_are_registered_for_every_day() {
+        let year = crate::year!(2024);
+        for day in all_days() {
+            assert!(
+                super::get(year, day).is_some(),
+                "no generator for day {day}"
+            );
+        }
+        assert_eq!(generators().len(), 25);
+    }
+
+    #[test]
+    fn generators_are_deterministic() {
+        for g in generators() {
+            let size = g.generator.test_size();
+            assert_eq!(
+                g.generator.generate(42, size).input,
+                g.generator.generate(42, size).input,
+                "day {} is not deterministic",
+                g.day
+            );
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/// Helpers that query the git repository the template lives in.
+use std::process::Command;
+
+/// Returns the full hash of the commit that `reference` points to, or `None` if git is not available.
+pub fn resolve(reference: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet", reference])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    if hash.is_empty() {
+        None
+    } else {
+        Some(hash)
+    }
+}
+
+/// Returns the full hash of the currently checked out commit.
+pub fn head_commit() -> Option<String> {
+    resolve("HEAD")
+}
+
+/// Shortens a commit hash for display.
+pub fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
@@ -0,0 +1,256 @@
+/// Counts heap allocations of solution parts.
+/// The counting allocator wraps the system allocator and is installed as global allocator unless DHAT is used.
+/// Allocations are only counted while [`measure`] runs a part with `--alloc`. Otherwise, an allocation only adds a relaxed
+/// atomic load, which is within the noise of benchmarks (see the readme).
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+};
+use tinyjson::JsonValue;
+
+/// Whether allocations of parts are measured, set with `--alloc`.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Whether the allocator counts allocations, only set while [`measure`] runs.
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// NOTE: signed, as blocks allocated before counting was enabled may be freed while counting.
+static LIVE_BYTES: AtomicI64 = AtomicI64::new(0);
+static PEAK_BYTES: AtomicI64 = AtomicI64::new(0);
+
+/// Heap usage of a single run of a solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Sum of the sizes of all allocations.
+    pub bytes: u64,
+    /// Maximum number of bytes that were allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+/// A global allocator that counts allocations while a part is measured.
+pub struct CountingAlloc;
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_dealloc(size: usize) {
+    LIVE_BYTES.fetch_sub(size as i64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if COUNTING.load(Ordering::Relaxed) {
+            record_dealloc(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Enables measuring the allocations of parts with [`measure`] for the rest of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether allocations are measured. Always `false` if the counting allocator is not installed, e.g. with DHAT.
+pub fn is_enabled() -> bool {
+    cfg!(not(feature = "dhat-heap")) && ENABLED.load(Ordering::Relaxed)
+}
+
+/// Runs `func` and returns the allocations it made, if measuring is enabled.
+/// Allocations are only counted while `func` runs.
+///
+/// NOTE: the counters are global, allocations of other threads that run at the same time are included.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    LIVE_BYTES.store(0, Ordering::Relaxed);
+    PEAK_BYTES.store(0, Ordering::Relaxed);
+
+    let result = {
+        let _counting = Counting::start();
+        func()
+    };
+
+    #[allow(clippy::cast_sign_loss)]
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).max(0) as u64,
+    };
+
+    (result, Some(stats))
+}
+
+/// Counts allocations until dropped, also if the measured part panics.
+struct Counting;
+
+impl Counting {
+    fn start() -> Self {
+        COUNTING.store(true, Ordering::Relaxed);
+        Self
+    }
+}
+
+impl Drop for Counting {
+    fn drop(&mut self) {
+        COUNTING.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl AllocStats {
+    /// Formats the stats for the readme and terminal output, e.g. `12 allocs, 4.0 KiB, peak 1.0 KiB`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+
+    /// Adds the stats to a JSON object, using the keys `allocations`, `alloc_bytes` and `peak_bytes`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn insert_json(&self, map: &mut HashMap<String, JsonValue>) {
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(self.allocations as f64),
+        );
+        map.insert("alloc_bytes".into(), JsonValue::Number(self.bytes as f64));
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(self.peak_bytes as f64),
+        );
+    }
+
+    /// Reads stats written by [`AllocStats::insert_json`] from a JSON object.
+    /// Returns `None` if the object has no stats. `name` is used in error messages, e.g. `report`.
+    pub fn from_json(
+        json: &HashMap<String, JsonValue>,
+        name: &str,
+    ) -> Result<Option<Self>, String> {
+        if !json.contains_key("allocations") {
+            return Ok(None);
+        }
+
+        let get_number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let v = *v as u64;
+                    v
+                })
+                .ok_or(format!("Expected {name}.{key} to be a number."))
+        };
+
+        Ok(Some(AllocStats {
+            allocations: get_number("allocations")?,
+            bytes: get_number("alloc_bytes")?,
+            peak_bytes: get_number("peak_bytes")?,
+        }))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{enable, format_bytes, is_enabled, measure, AllocStats};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn summarizes_stats() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 1024,
+        };
+        assert_eq!(stats.summary(), "12 allocs, 4.0 KiB, peak 1.0 KiB");
+    }
+
+    #[test]
+    fn counts_allocations() {
+        enable();
+        assert!(is_enabled());
+
+        let (len, stats) = measure(|| {
+            let a: Vec<u64> = Vec::with_capacity(128);
+            let b: Vec<u64> = Vec::with_capacity(128);
+            a.capacity() + b.capacity()
+        });
+
+        assert_eq!(len, 256);
+        let stats = stats.unwrap();
+        // NOTE: other tests may allocate at the same time.
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 2048);
+        assert!(stats.peak_bytes >= 2048);
+    }
+}
@@ -0,0 +1,222 @@
+//! Helpers to normalise puzzle inputs and split them into their parts.
+//! Inputs returned by [`read_file`](crate::template::read_file) are already normalised.
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::template::{try_read_file, try_read_file_part, Day, Year};
+
+/// Where a solution binary reads its input from.
+///
+/// Selected via `--input <path>`, `--input -` (stdin) or `--example[=N]`, defaults to `data/<year>/inputs/<day>.txt`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    #[default]
+    Puzzle,
+    Path(PathBuf),
+    Stdin,
+    /// An example file, optionally with a part suffix, e.g. `01-2.txt`.
+    Example(Option<u8>),
+}
+
+impl InputSource {
+    /// Creates the source from the values of `--input` and `--example`.
+    /// `example` is `Some(None)` if `--example` was passed without a number.
+    pub fn new(input: Option<&str>, example: Option<Option<&str>>) -> Result<Self, String> {
+        match (input, example) {
+            (Some(_), Some(_)) => Err("`--input` can't be combined with `--example`.".into()),
+            (Some("-"), None) => Ok(Self::Stdin),
+            (Some(path), None) => Ok(Self::Path(path.into())),
+            (None, Some(n)) => n
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| format!("invalid example number `{n}`."))
+                })
+                .transpose()
+                .map(Self::Example),
+            (None, None) => Ok(Self::default()),
+        }
+    }
+
+    /// Reads the input source from the arguments passed to the process.
+    /// The example number has to be attached, e.g. `--example=2`, so it's not mistaken for a day.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let input = match args.iter().position(|x| x == "--input") {
+            Some(index) => Some(
+                args.get(index + 1)
+                    .filter(|path| !path.starts_with("--"))
+                    .ok_or("expected a path or `-` for `--input`.")?
+                    .as_str(),
+            ),
+            None => None,
+        };
+
+        let example = match args
+            .iter()
+            .position(|x| x == "--example" || x.starts_with("--example="))
+        {
+            Some(index) => match args[index].strip_prefix("--example=") {
+                Some(n) => Some(Some(n)),
+                None => {
+                    if let Some(n) = args.get(index + 1).filter(|n| !n.starts_with("--")) {
+                        return Err(format!(
+                            "`--example` takes an attached number, e.g. `--example={n}`."
+                        ));
+                    }
+                    Some(None)
+                }
+            },
+            None => None,
+        };
+
+        Self::new(input, example)
+    }
+
+    /// Converts the source back to arguments, so it can be mirrored to child invocations.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Path(path) => vec!["--input".into(), path.display().to_string()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(n)) => vec![format!("--example={n}")],
+        }
+    }
+
+    /// Reads and normalises the input of a day from this source.
+    pub fn read(&self, year: Year, day: Day) -> io::Result<String> {
+        match self {
+            Self::Puzzle => try_read_file("inputs", year, day),
+            Self::Path(path) => fs::read_to_string(path).map(|s| normalize(&s)),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(normalize(&input))
+            }
+            Self::Example(None) => try_read_file("examples", year, day),
+            Self::Example(Some(n)) => try_read_file_part("examples", year, day, *n),
+        }
+    }
+}
+
+/// Unifies line endings to `\n` and removes trailing whitespace at the end of the input,
+/// e.g. the final newline of downloaded inputs. Whitespace inside of lines is left as is.
+#[must_use]
+pub fn normalize(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end().to_string()
+}
+
+/// Splits a normalised input into sections separated by blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n").filter(|section| !section.is_empty())
+}
+
+/// Splits a normalised input into its lines.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines()
+}
+
+/// Parses a normalised input into a grid of characters, indexed by row and column.
+#[must_use]
+pub fn grid(input: &str) -> Vec<Vec<char>> {
+    grid_with(input, |c| c)
+}
+
+/// Parses a normalised input into a grid, converting every character with `f`.
+pub fn grid_with<T>(input: &str, mut f: impl FnMut(char) -> T) -> Vec<Vec<T>> {
+    lines(input)
+        .map(|line| line.chars().map(&mut f).collect())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{grid, grid_with, lines, normalize, sections, InputSource};
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc");
+    }
+
+    #[test]
+    fn normalizes_trailing_whitespace() {
+        assert_eq!(normalize("a\nb\n\n"), "a\nb");
+        assert_eq!(normalize("  a  \nb  \n"), "  a  \nb");
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = normalize("1|2\r\n3|4\r\n\r\n1,2,3\r\n");
+        let result: Vec<_> = sections(&input).collect();
+        assert_eq!(result, vec!["1|2\n3|4", "1,2,3"]);
+    }
+
+    #[test]
+    fn splits_lines() {
+        let result: Vec<_> = lines("ab\ncd").collect();
+        assert_eq!(result, vec!["ab", "cd"]);
+    }
+
+    #[test]
+    fn parses_grids() {
+        assert_eq!(grid("#.\n.#"), vec![vec!['#', '.'], vec!['.', '#']]);
+        assert_eq!(
+            grid_with("12\n34", |c| c.to_digit(10).unwrap()),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(InputSource::from_args(&[]), Ok(InputSource::Puzzle));
+        assert_eq!(
+            InputSource::from_args(&args(&["--input", "a.txt"])),
+            Ok(InputSource::Path("a.txt".into()))
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--input", "-", "--time"])),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--example", "--time"])),
+            Ok(InputSource::Example(None))
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--example=2"])),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--examples"])),
+            Ok(InputSource::Puzzle)
+        );
+    }
+
+    #[test]
+    fn errors_for_invalid_input_sources() {
+        assert!(InputSource::from_args(&args(&["--input"])).is_err());
+        assert!(InputSource::from_args(&args(&["--example=x"])).is_err());
+        assert!(InputSource::from_args(&args(&["--example", "12"])).is_err());
+        assert!(InputSource::from_args(&args(&["--input", "a", "--example"])).is_err());
+    }
+
+    #[test]
+    fn roundtrips_input_source_args() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Path("a.txt".into()),
+            InputSource::Stdin,
+            InputSource::Example(None),
+            InputSource::Example(Some(2)),
+        ] {
+            assert_eq!(InputSource::from_args(&source.to_args()), Ok(source));
+        }
+    }
+}
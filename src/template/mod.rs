@@ -1,68 +1,145 @@
-use std::{env, fs};
+use std::{env, fs, io, path::PathBuf};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod compare;
+pub mod generator;
+pub mod heap;
+pub mod input;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod span;
+pub mod submissions;
 
 pub use day::*;
+pub use timings::MergePolicy;
+pub use year::*;
 
+mod bench_history;
 mod day;
+mod environment;
+mod git;
+mod readme;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod watch;
+mod year;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOC: heap::CountingAlloc = heap::CountingAlloc;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the data directory of a year, e.g. `data/2024`.
+#[must_use]
+pub fn data_dir(year: Year) -> PathBuf {
+    PathBuf::from("data").join(year.to_string())
+}
+
 /// Helper function that reads a text file to a string.
+/// The content is normalised with [`input::normalize`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    let f = try_read_file(folder, year, day);
     f.expect("could not open input file")
 }
 
+/// Helper function that reads a text file to a string, returning an error if the file can't be read.
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd
+        .join(data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
+    fs::read_to_string(filepath).map(|s| input::normalize(&s))
+}
+
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    let f = try_read_file_part(folder, year, day, part);
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads a text file with a part suffix, returning an error if the file can't be read.
+pub fn try_read_file_part(folder: &str, year: Year, day: Day, part: u8) -> io::Result<String> {
+    let cwd = env::current_dir()?;
     let filepath = cwd
-        .join("data")
+        .join(data_dir(year))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    fs::read_to_string(filepath).map(|s| input::normalize(&s))
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// Also creates the constant `SOLUTION`, which registers the parts in [`registry`].
+///
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Alternatively, the third parameter registers named variants of parts. They are run and benched after the part
+/// and their answers have to match the part's answer, e.g. `variants { 2 => [part_two_st, part_two_mt] }`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2];);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1];);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2];);
     };
+    ($year:expr, $day:expr, variants { $( $vpart:literal => [ $( $variant:ident ),+ $(,)? ] ),+ $(,)? }) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]; $( $( [$variant, $vpart] )+ )+);
+    };
+
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )* ; $( [$variant:ident, $vpart:expr] )*) => {
+        /// The year of the current solution.
+        const YEAR: $crate::template::Year = $crate::year!($year);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The parts of the current day, used to run the solution in-process.
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                year: YEAR,
+                day: DAY,
+                parts: &[
+                    $( ($part, |input: &str| $func(input).map(|x| x.to_string())), )*
+                ],
+                variants: &[
+                    $( ($vpart, stringify!($variant), |input: &str| $variant(input).map(|x| x.to_string())), )*
+                ],
+            };
 
+        #[allow(dead_code)]
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            // NOTE: parts may outlive `main` if they time out, so the input has to be `'static`.
+            check_selected_part(&SOLUTION);
+            let input: &'static str = read_input(YEAR, DAY).leak();
+            let mut variants_agree = true;
+            $(
+                if is_part_selected($part) {
+                    let report = run_part($func, input, YEAR, DAY, $part);
+                    variants_agree &= run_variants(&SOLUTION, input, &report);
+                }
+            )*
+            if !variants_agree {
+                std::process::exit(1);
+            }
         }
     };
 }
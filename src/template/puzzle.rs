@@ -0,0 +1,184 @@
+/// Parses the markdown of a downloaded puzzle description, e.g. `data/2024/puzzles/01.md`.
+/// Used to extract the examples and the answers the puzzle states for them.
+use std::{fs, io, path::PathBuf};
+
+use crate::template::{data_dir, Day, Year};
+
+/// A code block of the puzzle description, usually an example input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The position of the block in the description, starting at 1.
+    pub index: usize,
+    /// The part of the puzzle the block appears in.
+    pub part: u8,
+    pub content: String,
+}
+
+/// An answer stated by the puzzle description for one of its code blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatedAnswer {
+    /// The index of the code block the answer refers to.
+    pub block: usize,
+    pub part: u8,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub blocks: Vec<CodeBlock>,
+    pub answers: Vec<StatedAnswer>,
+}
+
+impl Puzzle {
+    /// Reads the puzzle description of a day from the data directory.
+    pub fn read_from_file(year: Year, day: Day) -> Result<Self, io::Error> {
+        fs::read_to_string(get_path(year, day)).map(|s| Self::parse(&s))
+    }
+
+    /// Extracts code blocks and stated answers from the puzzle markdown.
+    ///
+    /// Answers are emphasized inline code (e.g. `*11*`). A part may state several values
+    /// for an example, the last one that follows a block is taken as the answer for it.
+    /// If part two does not repeat an example, its answers refer to the last block of part one.
+    pub fn parse(markdown: &str) -> Self {
+        let mut puzzle = Puzzle::default();
+
+        let mut part = 1;
+        let mut block: Option<Vec<&str>> = None;
+
+        for line in markdown.lines() {
+            if line.starts_with("```") {
+                match block.take() {
+                    Some(lines) => puzzle.blocks.push(CodeBlock {
+                        index: puzzle.blocks.len() + 1,
+                        part,
+                        content: lines.join("\n"),
+                    }),
+                    None => block = Some(vec![]),
+                }
+                continue;
+            }
+
+            if let Some(lines) = block.as_mut() {
+                lines.push(line);
+                continue;
+            }
+
+            if line.starts_with("## ") && line.contains("Part Two") {
+                part = 2;
+            }
+
+            let Some(current) = puzzle.blocks.last().map(|b| b.index) else {
+                continue;
+            };
+
+            for value in emphasized_code(line) {
+                puzzle
+                    .answers
+                    .retain(|a| !(a.block == current && a.part == part));
+                puzzle.answers.push(StatedAnswer {
+                    block: current,
+                    part,
+                    value,
+                });
+            }
+        }
+
+        puzzle
+    }
+
+    pub fn block(&self, index: usize) -> Option<&CodeBlock> {
+        self.blocks.iter().find(|b| b.index == index)
+    }
+
+    /// Returns the stated answers that refer to a block.
+    pub fn answers_for(&self, block: usize) -> impl Iterator<Item = &StatedAnswer> {
+        self.answers.iter().filter(move |a| a.block == block)
+    }
+}
+
+/// Returns the values of inline code that is emphasized, i.e. `*x*` or *`x`*.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let segments: Vec<&str> = line.split('`').collect();
+
+    // NOTE: segments at odd positions are inside backticks.
+    (1..segments.len().saturating_sub(1))
+        .step_by(2)
+        .filter_map(|i| {
+            let code = segments[i];
+
+            if code.len() > 2 && code.starts_with('*') && code.ends_with('*') {
+                Some(code[1..code.len() - 1].to_string())
+            } else if segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*') {
+                Some(code.to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn get_path(year: Year, day: Day) -> PathBuf {
+    data_dir(year).join("puzzles").join(format!("{day}.md"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{emphasized_code, Puzzle};
+
+    const PUZZLE: &str = "## --- Day 1: Historian Hysteria ---
+
+For example:
+
+```
+3   4
+4   3
+```
+
+The first pair has a distance of `*2*`. In the example above, this is `2 + 1 = *11*`!
+
+To find the *total distance*, add up all distances: `*11*`.
+
+Another example:
+
+```
+1   1
+```
+
+Here, the total distance is *`0`*.
+
+## --- Part Two ---
+
+For the example above, the similarity score is `*31*`.
+";
+
+    #[test]
+    fn extracts_code_blocks() {
+        let puzzle = Puzzle::parse(PUZZLE);
+        assert_eq!(puzzle.blocks.len(), 2);
+        assert_eq!(puzzle.blocks[0].index, 1);
+        assert_eq!(puzzle.blocks[0].part, 1);
+        assert_eq!(puzzle.blocks[0].content, "3   4\n4   3");
+        assert_eq!(puzzle.block(2).unwrap().content, "1   1");
+    }
+
+    #[test]
+    fn extracts_stated_answers() {
+        let puzzle = Puzzle::parse(PUZZLE);
+        let answers: Vec<_> = puzzle
+            .answers
+            .iter()
+            .map(|a| (a.block, a.part, a.value.as_str()))
+            .collect();
+        assert_eq!(answers, vec![(1, 1, "11"), (2, 1, "0"), (2, 2, "31")]);
+        assert_eq!(puzzle.answers_for(1).count(), 1);
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(emphasized_code("a `*1*` b *`2`* `3` *4*"), vec!["1", "2"]);
+        assert!(emphasized_code("no code").is_empty());
+    }
+}
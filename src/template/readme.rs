@@ -0,0 +1,269 @@
+/// Module that updates the readme with the ⭐️ progress of a year.
+/// Tables in the readme are delimited by a pair of markers, the content between them is regenerated.
+use std::{fmt::Display, fs, io};
+
+use crate::template::answers::Answers;
+use crate::template::aoc_client::AocClient;
+use crate::template::{all_days, Day, Year};
+
+/// Every year has its own table, delimited by a marker that contains the year.
+fn marker(year: Year) -> String {
+    format!("<!--- advent_readme_stars table {year} --->")
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+    /// The progress could not be read from its source.
+    Progress(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) | Error::Progress(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+pub struct TablePosition {
+    pub pos_start: usize,
+    pub pos_end: usize,
+}
+
+/// Finds the table between the first and the last occurrence of `marker`, including the markers.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
+    }
+
+    let pos_start = matches.first().map(|m| m.0).ok_or_else(|| {
+        Error::Parser(format!("Could not find table start position for {marker}."))
+    })?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+    Ok(TablePosition { pos_start, pos_end })
+}
+
+/// Replaces the table delimited by `marker` with `table`. The table is expected to contain the markers.
+pub fn replace_table(readme: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(readme, marker)?;
+    readme.replace_range(positions.pos_start..positions.pos_end, table);
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The parts solved on a day, each part earns a star.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: Day,
+    pub solved: [bool; 2],
+}
+
+impl DayProgress {
+    pub fn is_solved(&self, part: u8) -> bool {
+        self.solved[usize::from(part) - 1]
+    }
+
+    pub fn stars(&self) -> usize {
+        self.solved.iter().filter(|solved| **solved).count()
+    }
+}
+
+/// Provides the per-part completion of the days of a year.
+pub trait ProgressSource {
+    /// Returns the days with at least one star, ordered by day.
+    fn progress(&self, year: Year) -> Result<Vec<DayProgress>, Error>;
+}
+
+/// Reads progress from the answer ledger. Every stored answer counts as a star.
+pub struct AnswerLedger;
+
+impl ProgressSource for AnswerLedger {
+    fn progress(&self, year: Year) -> Result<Vec<DayProgress>, Error> {
+        let mut progress = vec![];
+
+        for day in all_days() {
+            let answers = Answers::read_from_file(year, day).map_err(Error::Progress)?;
+            let day = DayProgress {
+                day,
+                solved: [answers.part_1.is_some(), answers.part_2.is_some()],
+            };
+            if day.stars() > 0 {
+                progress.push(day);
+            }
+        }
+
+        Ok(progress)
+    }
+}
+
+/// Reads progress from the calendar page of the Advent of Code website.
+impl ProgressSource for AocClient {
+    fn progress(&self, year: Year) -> Result<Vec<DayProgress>, Error> {
+        let stars = self
+            .get_stars()
+            .map_err(|e| Error::Progress(format!("failed to fetch progress for {year}: {e}")))?;
+
+        Ok(stars
+            .into_iter()
+            .filter(|(_, stars)| *stars > 0)
+            .map(|(day, stars)| DayProgress {
+                day,
+                solved: [stars >= 1, stars >= 2],
+            })
+            .collect())
+    }
+}
+
+fn construct_table(year: Year, progress: &[DayProgress]) -> String {
+    let mut lines: Vec<String> = vec![
+        marker(year),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let cell = |day: &DayProgress, part: u8| if day.is_solved(part) { " ⭐ " } else { " " };
+
+    for day in progress {
+        let n = day.day.into_inner();
+        lines.push(format!(
+            "| [Day {n}](https://adventofcode.com/{year}/day/{n}) |{}|{}|",
+            cell(day, 1),
+            cell(day, 2)
+        ));
+    }
+
+    lines.push(marker(year));
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    source: &impl ProgressSource,
+) -> Result<usize, Error> {
+    let progress = source.progress(year)?;
+    let table = construct_table(year, &progress);
+    replace_table(s, &marker(year), &table)?;
+    Ok(progress.iter().map(DayProgress::stars).sum())
+}
+
+/// Regenerates the stars table of the readme. Returns the total number of stars.
+pub fn update_stars(year: Year, source: &impl ProgressSource) -> Result<usize, Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let stars = update_content(&mut readme, year, source)?;
+    fs::write(path, &readme)?;
+    Ok(stars)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{locate_table, marker, update_content, DayProgress, Error, ProgressSource};
+    use crate::{day, template::Year, year};
+
+    struct MockProgress(Vec<DayProgress>);
+
+    impl ProgressSource for MockProgress {
+        fn progress(&self, _year: Year) -> Result<Vec<DayProgress>, Error> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn mock_progress() -> MockProgress {
+        MockProgress(vec![
+            DayProgress {
+                day: day!(1),
+                solved: [true, true],
+            },
+            DayProgress {
+                day: day!(2),
+                solved: [true, false],
+            },
+            DayProgress {
+                day: day!(3),
+                solved: [false, true],
+            },
+        ])
+    }
+
+    #[test]
+    fn locates_table_between_markers() {
+        let marker = marker(year!(2024));
+        let s = format!("foo\n{marker}\ntable\n{marker}\nbar");
+        let position = locate_table(&s, &marker).unwrap();
+        assert_eq!(
+            &s[position.pos_start..position.pos_end],
+            format!("{marker}\ntable\n{marker}")
+        );
+
+        assert!(locate_table("# readme", &marker).is_err());
+        assert!(locate_table(&marker.repeat(3), &marker).is_err());
+    }
+
+    #[test]
+    fn format_stars() {
+        let marker = marker(year!(2024));
+        let mut s = format!("foo\n{marker}\n{marker}\nbar");
+        let stars = update_content(&mut s, year!(2024), &mock_progress()).unwrap();
+        let expected = [
+            "foo",
+            &marker,
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2024/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2024/day/2) | ⭐ | |",
+            "| [Day 3](https://adventofcode.com/2024/day/3) | | ⭐ |",
+            &marker,
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+        assert_eq!(stars, 4);
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let marker = marker(year!(2024));
+        let mut s = format!("{marker}\n{marker}");
+        update_content(&mut s, year!(2024), &mock_progress()).unwrap();
+        update_content(&mut s, year!(2024), &MockProgress(vec![])).unwrap();
+        assert_eq!(s.matches(&marker).count(), 2);
+        assert!(!s.contains("[Day 1]"));
+    }
+
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let (marker_2023, marker_2024) = (marker(year!(2023)), marker(year!(2024)));
+        let mut s = format!("{marker_2024}\n{marker_2024}\n{marker_2023}\n{marker_2023}");
+        update_content(&mut s, year!(2024), &mock_progress()).unwrap();
+        update_content(&mut s, year!(2023), &MockProgress(vec![])).unwrap();
+        assert!(s.contains("## 2024 Results"));
+        assert!(s.contains("## 2023 Results"));
+        assert!(s.contains("[Day 1](https://adventofcode.com/2024/day/1)"));
+    }
+}
@@ -1,129 +1,129 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::fs;
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::heap::AllocStats;
+use crate::template::readme::{replace_table, Error};
+use crate::template::timings::{format_nanos, Timings};
+use crate::template::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
-
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    Parser(String),
-    IO(io::Error),
-}
-
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
-
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+/// Every year has its own table, delimited by a marker that contains the year.
+fn marker(year: Year) -> String {
+    format!("<!--- benchmarking table {year} --->")
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
-
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+/// Heap columns are only added if allocations were counted for at least one day.
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+    let show_heap = timings
+        .data
+        .iter()
+        .flat_map(|t| &t.parts)
+        .any(|p| p.alloc.is_some());
+
+    let mut lines: Vec<String> = vec![marker(year), header, String::new()];
+
+    if show_heap {
+        lines.push("| Day | Part 1 | Part 2 | Part 1 Heap | Part 2 Heap |".into());
+        lines.push("| :---: | :---: | :---:  | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
-
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
-
-    Ok(TablePosition { pos_start, pos_end })
-}
-
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
-
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
+        let path = get_path_for_bin(year, timing.day);
+        let cell = |part: u8| match (timing.part_nanos(part), timing.failure(part)) {
+            (Some(nanos), _) => format_nanos(nanos),
+            (None, Some(status)) => status.to_string().replace('_', " "),
+            (None, None) => "-".into(),
+        };
+        let mut line = format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            cell(1),
+            cell(2)
+        );
+        if show_heap {
+            for part in [1, 2] {
+                let heap = timing
+                    .allocations(part)
+                    .map_or_else(|| "-".into(), AllocStats::summary);
+                line.push_str(&format!(" `{heap}` |"));
+            }
+        }
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let table = construct_table("##", year, timings, total_millis);
+    replace_table(s, &marker(year), &table)
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::update_content;
+    use crate::{
+        day,
+        template::heap::AllocStats,
+        template::report::PartStatus,
+        template::timings::{PartTiming, Timing, Timings},
+        template::Day,
+        year,
+    };
+
+    const MARKER: &str = "<!--- benchmarking table 2024 --->";
+
+    fn timing(day: Day, part_1: f64, part_2: f64) -> Timing {
+        let part = |part, nanos| PartTiming {
+            part,
+            nanos,
+            samples: Some(10),
+            status: PartStatus::Solved,
+            alloc: None,
+        };
+
+        Timing {
+            day,
+            parts: vec![part(1, part_1), part(2, part_2)],
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
-                },
+                timing(day!(1), 10e+6, 20e+6),
+                timing(day!(2), 30e+6, 40e+6),
+                timing(day!(4), 40e+6, 50e+6),
             ],
+            history: vec![],
         }
     }
 
@@ -131,53 +131,82 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn updates_benchmarks_of_year() {
+        let other = "<!--- benchmarking table 2023 --->";
+        let mut s = format!("{other}\n{other}\n{MARKER}\n{MARKER}");
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
+        assert!(s.starts_with(&format!("{other}\n{other}\n{MARKER}\n## 2024 Benchmarks")));
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2024 --->",
+            "## 2024 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2024 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_heap_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].parts[1].alloc = Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 1024,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2024), timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 Heap | Part 2 Heap |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` | `-` | `12 allocs, 4.0 KiB, peak 1.0 KiB` |"
+        ));
+        assert!(s.contains("| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` | `-` | `-` |"));
+    }
 }
@@ -0,0 +1,67 @@
+/// Lookup of the solutions that are compiled into the library.
+/// This allows running multiple days inside a single process, without spawning a binary per day.
+use crate::template::{Day, Year};
+
+/// A type-erased solution part. The answer is converted to a string so parts of different days can be stored together.
+pub type PartFn = fn(&str) -> Option<String>;
+
+/// The solution parts of a single day. Created by the [`solution!`](crate::solution) macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    pub parts: &'static [(u8, PartFn)],
+    /// Alternative implementations of parts, with their function name. They are cross-checked against the part.
+    pub variants: &'static [(u8, &'static str, PartFn)],
+}
+
+impl Solution {
+    /// Returns the function for a given part, if the day implements it.
+    pub fn part(&self, part: u8) -> Option<PartFn> {
+        self.parts.iter().find(|(p, _)| *p == part).map(|(_, f)| *f)
+    }
+
+    /// Returns the names and functions of the variants of a given part.
+    pub fn variants(&self, part: u8) -> impl Iterator<Item = (&'static str, PartFn)> {
+        self.variants
+            .iter()
+            .filter(move |(p, _, _)| *p == part)
+            .map(|(_, name, f)| (*name, *f))
+    }
+}
+
+/// All registered solutions, ordered by year and day.
+pub fn solutions() -> &'static [Solution] {
+    crate::solutions::SOLUTIONS
+}
+
+/// Returns the registered solution for a day of a year, if there is one.
+pub fn get(year: Year, day: Day) -> Option<&'static Solution> {
+    solutions().iter().find(|s| s.year == year && s.day == day)
+}
+
+/// Returns an error if a registered day of `days` does not implement `part`, e.g. the second part of the last day.
+pub fn check_part<'a>(
+    year: Year,
+    days: impl IntoIterator<Item = &'a Day>,
+    part: u8,
+) -> Result<(), String> {
+    let mut missing: Vec<Day> = days
+        .into_iter()
+        .filter(|day| get(year, **day).is_some_and(|s| s.part(part).is_none()))
+        .copied()
+        .collect();
+    missing.sort_unstable();
+
+    match missing.as_slice() {
+        [] => Ok(()),
+        [day] => Err(format!("day {day} has no part {part}.")),
+        days => Err(format!(
+            "days {} have no part {part}.",
+            days.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
@@ -0,0 +1,537 @@
+/// Machine-readable results of running a solution part.
+/// Solution binaries print one JSON line per part when invoked with `--format json`.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::{heap::AllocStats, runner::BenchStats, span::PhaseTiming, Day};
+
+/// Output format of a solution binary, selected via `--format <text|json>`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the output format from the arguments passed to the process.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        match args.iter().position(|x| x == "--format") {
+            Some(index) => args
+                .get(index + 1)
+                .ok_or_else(|| "expected a value for `--format`.".to_string())
+                .and_then(|x| x.parse()),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            x => Err(format!(
+                "unknown output format `{x}`, expecting `text` or `json`."
+            )),
+        }
+    }
+}
+
+/// Outcome of running a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part did not finish within its time budget.
+    TimedOut,
+    /// The part panicked. The location is the source position of the panic, e.g. `src/bin/2024-05.rs:12:42`.
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+}
+
+impl PartStatus {
+    /// Whether the part ran but did not finish.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, PartStatus::TimedOut | PartStatus::Panicked { .. })
+    }
+
+    /// Adds the status to a JSON object, using the `status` key and for panics, `message` and `location`.
+    pub fn insert_json(&self, map: &mut HashMap<String, JsonValue>) {
+        map.insert("status".into(), JsonValue::String(self.to_string()));
+
+        if let PartStatus::Panicked { message, location } = self {
+            map.insert("message".into(), JsonValue::String(message.clone()));
+            map.insert(
+                "location".into(),
+                match location {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+    }
+
+    /// Reads a status written by [`PartStatus::insert_json`] from a JSON object.
+    /// `name` is used in error messages, e.g. `report`.
+    pub fn from_json(json: &HashMap<String, JsonValue>, name: &str) -> Result<Self, String> {
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or(format!("Expected {name}.status to be a string."))?;
+
+        if status != "panicked" {
+            return status.parse();
+        }
+
+        let message = json
+            .get("message")
+            .and_then(|v| v.get::<String>())
+            .ok_or(format!("Expected {name}.message to be a string."))?;
+
+        let location = json
+            .get("location")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or(format!("Expected {name}.location to be null or string."))?;
+
+        Ok(PartStatus::Panicked {
+            message: message.clone(),
+            location: location.cloned(),
+        })
+    }
+}
+
+/// Reads the part number of a JSON object. `name` is used in error messages, e.g. `report`.
+pub fn part_from_json(json: &HashMap<String, JsonValue>, name: &str) -> Result<u8, String> {
+    json.get("part")
+        .and_then(|v| v.get::<f64>())
+        .filter(|v| **v == 1.0 || **v == 2.0)
+        .map(|v| if *v == 1.0 { 1 } else { 2 })
+        .ok_or(format!("Expected {name}.part to be 1 or 2."))
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Solved => f.write_str("solved"),
+            PartStatus::Unsolved => f.write_str("unsolved"),
+            PartStatus::TimedOut => f.write_str("timed_out"),
+            PartStatus::Panicked { .. } => f.write_str("panicked"),
+        }
+    }
+}
+
+/// Parses the statuses without details. Panics are read with [`PartStatus::from_json`].
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Self::Solved),
+            "unsolved" => Ok(Self::Unsolved),
+            "timed_out" => Ok(Self::TimedOut),
+            x => Err(format!("unknown part status `{x}`.")),
+        }
+    }
+}
+
+/// Result and timing information for a single solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartReport {
+    pub day: Day,
+    pub part: u8,
+    /// Name of the variant that was run instead of the part, see [`solution!`](crate::solution).
+    pub variant: Option<String>,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+    /// Heap usage of the first run, if allocations were counted with `--alloc`.
+    pub alloc: Option<AllocStats>,
+    /// Time spent in the phases the part marked with spans.
+    pub phases: Vec<PhaseTiming>,
+}
+
+impl PartReport {
+    pub fn new<T: Display + ?Sized>(
+        day: Day,
+        part: u8,
+        answer: Option<&T>,
+        stats: BenchStats,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            variant: None,
+            status: match answer {
+                Some(_) => PartStatus::Solved,
+                None => PartStatus::Unsolved,
+            },
+            answer: answer.map(ToString::to_string),
+            stats,
+            alloc: None,
+            phases: vec![],
+        }
+    }
+
+    /// Creates the report of a part that did not finish, e.g. because it timed out after `elapsed`.
+    pub fn failed(day: Day, part: u8, status: PartStatus, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            variant: None,
+            status,
+            answer: None,
+            // NOTE: the report stores the elapsed time as duration, it is not counted towards total times.
+            stats: BenchStats::from_samples(&[elapsed]).unwrap(),
+            alloc: None,
+            phases: vec![],
+        }
+    }
+
+    /// Returns the name of the part for output, e.g. `Part 2` or `Part 2 (part_two_st)` for variants.
+    pub fn label(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("Part {} ({variant})", self.part),
+            None => format!("Part {}", self.part),
+        }
+    }
+
+    /// Serializes the report to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        // NOTE: stringifying a JSON object can't fail.
+        JsonValue::from(self).stringify().unwrap()
+    }
+
+    /// Parses a line printed in `--format json` mode.
+    /// Returns `None` if the line is not a part report, e.g. debug output of a solution.
+    pub fn from_json_line(line: &str) -> Option<Result<Self, String>> {
+        if !line.starts_with('{') {
+            return None;
+        }
+
+        let json = JsonValue::from_str(line).ok()?;
+        Some(PartReport::try_from(&json))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos_to_json(duration: &Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert(
+            "variant".into(),
+            match &value.variant {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        value.status.insert_json(&mut map);
+
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("duration_nanos".into(), nanos_to_json(&value.stats.average));
+        map.insert("min_nanos".into(), nanos_to_json(&value.stats.min));
+        map.insert("median_nanos".into(), nanos_to_json(&value.stats.median));
+        map.insert("p95_nanos".into(), nanos_to_json(&value.stats.p95));
+        map.insert("std_dev_nanos".into(), nanos_to_json(&value.stats.std_dev));
+
+        if let Some(alloc) = &value.alloc {
+            alloc.insert_json(&mut map);
+        }
+
+        map.insert(
+            "phases".into(),
+            JsonValue::Array(value.phases.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let part = part_from_json(json, "report")?;
+
+        // NOTE: reports of binaries built before variants were supported do not have the key.
+        let variant = match json.get("variant") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected report.variant to be null or string.")?
+                    .clone(),
+            ),
+            _ => None,
+        };
+
+        let status = PartStatus::from_json(json, "report")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let get_nanos = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    Duration::from_nanos(*v as u64)
+                })
+                .ok_or(format!("Expected report.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u128)
+            .ok_or("Expected report.samples to be a number.")?;
+
+        let alloc = AllocStats::from_json(json, "report")?;
+
+        // NOTE: reports of binaries built before phases were tracked do not have the key.
+        let phases = match json.get("phases") {
+            Some(phases) => phases
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected report.phases to be an array.")?
+                .iter()
+                .map(PhaseTiming::try_from)
+                .collect::<Result<_, _>>()?,
+            None => vec![],
+        };
+
+        Ok(PartReport {
+            day,
+            part,
+            variant,
+            status,
+            answer: answer.cloned(),
+            stats: BenchStats {
+                samples,
+                average: get_nanos("duration_nanos")?,
+                min: get_nanos("min_nanos")?,
+                median: get_nanos("median_nanos")?,
+                p95: get_nanos("p95_nanos")?,
+                std_dev: get_nanos("std_dev_nanos")?,
+            },
+            alloc,
+            phases,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PhaseTiming> for JsonValue {
+    fn from(value: &PhaseTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("name".into(), JsonValue::String(value.name.clone()));
+        map.insert("duration_nanos".into(), nanos_to_json(&value.duration));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PhaseTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected phase to be a JSON object.")?;
+
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected phase.name to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| Duration::from_nanos(*v as u64))
+            .ok_or("Expected phase.duration_nanos to be a number.")?;
+
+        Ok(PhaseTiming {
+            name: name.clone(),
+            duration,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartReport, PartStatus};
+    use crate::{
+        day,
+        template::{heap::AllocStats, runner::BenchStats, span::PhaseTiming},
+    };
+
+    fn get_mock_report(answer: Option<&str>) -> PartReport {
+        let samples: Vec<Duration> = [10, 20, 30].map(Duration::from_micros).to_vec();
+        PartReport::new(
+            day!(6),
+            2,
+            answer,
+            BenchStats::from_samples(&samples).unwrap(),
+        )
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = get_mock_report(Some("1721"));
+        let line = report.to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(PartReport::from_json_line(&line), Some(Ok(report)));
+    }
+
+    #[test]
+    fn roundtrips_answers_with_special_characters() {
+        let report = get_mock_report(Some("foo (1 @ 2 samples)\n#..#\n.##."));
+        let line = report.to_json_line();
+        assert!(!line.contains('\n'));
+        let parsed = PartReport::from_json_line(&line).unwrap().unwrap();
+        assert_eq!(parsed.answer, report.answer);
+        assert_eq!(parsed.status, PartStatus::Solved);
+    }
+
+    #[test]
+    fn roundtrips_unsolved_reports() {
+        let report = get_mock_report(None);
+        let parsed = PartReport::from_json_line(&report.to_json_line())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed.status, PartStatus::Unsolved);
+        assert_eq!(parsed.answer, None);
+    }
+
+    #[test]
+    fn roundtrips_timed_out_reports() {
+        let report = PartReport::failed(day!(6), 2, PartStatus::TimedOut, Duration::from_secs(10));
+        let parsed = PartReport::from_json_line(&report.to_json_line())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_panicked_reports() {
+        for location in [Some("src/bin/2024-05.rs:12:42".to_string()), None] {
+            let status = PartStatus::Panicked {
+                message: "Expected pair".into(),
+                location,
+            };
+            let report = PartReport::failed(day!(5), 1, status, Duration::from_millis(1));
+            let parsed = PartReport::from_json_line(&report.to_json_line())
+                .unwrap()
+                .unwrap();
+            assert_eq!(parsed, report);
+        }
+    }
+
+    #[test]
+    fn roundtrips_alloc_stats() {
+        let mut report = get_mock_report(Some("1721"));
+        report.alloc = Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 1024,
+        });
+        let parsed = PartReport::from_json_line(&report.to_json_line())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_phases() {
+        let mut report = get_mock_report(Some("1721"));
+        report.phases = vec![
+            PhaseTiming {
+                name: "parse".into(),
+                duration: Duration::from_micros(5),
+            },
+            PhaseTiming {
+                name: "solve".into(),
+                duration: Duration::from_micros(15),
+            },
+        ];
+        let parsed = PartReport::from_json_line(&report.to_json_line())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_variants() {
+        let mut report = get_mock_report(Some("1721"));
+        report.variant = Some("part_two_st".into());
+        assert_eq!(report.label(), "Part 2 (part_two_st)");
+        let parsed = PartReport::from_json_line(&report.to_json_line())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn ignores_non_report_lines() {
+        assert_eq!(PartReport::from_json_line("Solving [1 / 10]"), None);
+        assert_eq!(PartReport::from_json_line(""), None);
+    }
+
+    #[test]
+    fn errors_for_malformed_reports() {
+        let res = PartReport::from_json_line(r#"{ "day": "01", "part": 3 }"#);
+        assert!(res.unwrap().is_err());
+    }
+
+    #[test]
+    fn parses_output_format() {
+        let args = vec!["--time".to_string(), "--format".into(), "json".into()];
+        assert_eq!(OutputFormat::from_args(&args), Ok(OutputFormat::Json));
+        assert_eq!(OutputFormat::from_args(&[]), Ok(OutputFormat::Text));
+        assert!(OutputFormat::from_args(&["--format".to_string(), "xml".into()]).is_err());
+    }
+}
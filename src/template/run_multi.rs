@@ -1,20 +1,42 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    registry,
+    report::PartReport,
+    runner::{check_variant, has_detached_part, print_report, solve_part, BenchConfig},
+    try_read_file, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Runs the solutions of a year for `days_to_run`. Solutions are benched if a `bench` config is passed.
+/// Parts that take longer than `timeout` are reported as timed out and the remaining days keep running.
+/// As the part keeps running in the background, the remaining days are skipped when benching.
+/// With `variants`, the variants of each part are run after the part. Only `part` runs if it is set.
+///
+/// Returns the timings if solutions were benched, and whether all variants agreed with their parts.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    part: Option<u8>,
+    bench: Option<&BenchConfig>,
+    timeout: Option<Duration>,
+    variants: bool,
+) -> (Option<Timings>, bool) {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut variants_agree = true;
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
+    // NOTE: use non-duplicate, sorted day values. Days without the selected part are skipped.
     all_days()
         .filter(|day| days_to_run.contains(day))
+        .filter(|day| {
+            part.is_none_or(|part| registry::get(year, *day).is_none_or(|s| s.part(part).is_some()))
+        })
         .for_each(|day| {
             if need_space {
                 println!();
@@ -24,234 +46,109 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            // NOTE: benchmarks would be skewed by the part that is still running, the stored timings are kept.
+            if bench.is_some() && has_detached_part() {
+                println!("Skipped, a part that timed out is still running.");
+                return;
+            }
+
+            let (reports, agree) = run_solution(year, day, part, bench, timeout, variants, true);
+            variants_agree &= agree;
 
-            if output.is_empty() {
+            if reports.is_empty() {
                 println!("Not solved.");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                timings.push(Timing::from_reports(&reports, day));
             }
         });
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    let timings = bench.map(|_| {
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
-    }
-}
-
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
-}
-
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
+        timings
+    });
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    (timings, variants_agree)
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+/// Run the registered solution for a given day in-process, printing the results if `verbose` is set.
+/// Returns the reports of all parts that ran, which is none if the day is not registered or has no input.
+/// Only `part` runs if it is set.
+///
+/// With `variants`, the variants of each part are run after the part and their answers are cross-checked.
+/// Reports of variants are only printed, they are not returned. The second value is `false` if a variant disagreed.
+pub fn run_solution(
+    year: Year,
+    day: Day,
+    part: Option<u8>,
+    bench: Option<&BenchConfig>,
+    timeout: Option<Duration>,
+    variants: bool,
+    verbose: bool,
+) -> (Vec<PartReport>, bool) {
+    let Some(solution) = registry::get(year, day) else {
+        return (vec![], true);
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
-
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
-        }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
-
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
-
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
-
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+    let input: Arc<str> = match try_read_file("inputs", year, day) {
+        Ok(input) => input.into(),
+        Err(e) => {
+            if verbose {
+                eprintln!("Could not read input file: {e}");
+            }
+            return (vec![], true);
         }
+    };
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
-    }
-
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
+    let mut reports = vec![];
+    let mut variants_agree = true;
+
+    for (part, func) in solution
+        .parts
+        .iter()
+        .filter(|(x, _)| part.is_none_or(|part| part == *x))
+    {
+        let func = *func;
+        let (_, report) = solve_part(
+            move |input: Arc<str>| func(&input),
+            input.clone(),
             day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
-
-        timings
-    }
-
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
-
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            *part,
+            bench,
+            timeout,
+            verbose,
+        );
+        if verbose {
+            print_report(&report);
         }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        if variants {
+            for (name, func) in solution.variants(*part) {
+                let (_, mut variant) = solve_part(
+                    move |input: Arc<str>| func(&input),
+                    input.clone(),
+                    day,
+                    *part,
+                    bench,
+                    timeout,
+                    false,
+                );
+                variant.variant = Some(name.to_string());
+                if verbose {
+                    print_report(&variant);
+                }
+                variants_agree &= check_variant(&report, &variant);
+            }
         }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+        reports.push(report);
     }
+
+    (reports, variants_agree)
 }
@@ -1,81 +1,711 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::AssertUnwindSafe;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, panic, process, thread};
 
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, AocClientError, SubmissionOutcome};
+use crate::template::heap::{self, AllocStats};
+use crate::template::input::InputSource;
+use crate::template::registry::Solution;
+use crate::template::report::{OutputFormat, PartReport, PartStatus};
+use crate::template::span::{self, PhaseTiming};
+use crate::template::submissions::{self, Submission, Submissions};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Reads the input of a solution from the source selected by the arguments passed to the process.
+/// Answers for inputs other than the puzzle input can't be stored or submitted.
+pub fn read_input(year: Year, day: Day) -> String {
+    let args: Vec<String> = env::args().collect();
+
+    let source = InputSource::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    if source != InputSource::Puzzle && args.iter().any(|x| x == "--store" || x == "--submit") {
+        eprintln!("`--store` and `--submit` can only be used with the puzzle input.");
+        process::exit(1);
+    }
+
+    source.read(year, day).unwrap_or_else(|e| {
+        eprintln!("Could not read input: {e}");
+        process::exit(1);
+    })
+}
+
+/// Stack size of the thread that runs a part with a timeout.
+/// Solutions are written for the main thread, which has a larger stack than spawned threads by default.
+const PART_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Set once a part timed out. Its thread keeps running in the background and competes with later parts.
+static DETACHED_PART: AtomicBool = AtomicBool::new(false);
+
+/// Whether a part that timed out is still running in this process.
+/// Timings and allocations of later parts would be skewed by it, so they are not benched or measured.
+pub fn has_detached_part() -> bool {
+    DETACHED_PART.load(Ordering::Relaxed)
+}
+
+/// Options of a solution binary that control how parts are run.
+struct RunArgs {
+    format: OutputFormat,
+    timeout: Option<Duration>,
+    bench: Option<BenchConfig>,
+    variants: bool,
+    part: Option<u8>,
+}
+
+impl RunArgs {
+    /// Reads the options from the arguments passed to the process, exiting on invalid values.
+    /// Also enables counting of allocations if `--alloc` is passed.
+    fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let format = OutputFormat::from_args(&args).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        });
+
+        let timeout = timeout_from_args(&args).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        });
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+        if args.iter().any(|x| x == "--alloc") {
+            heap::enable();
+        }
+
+        let bench = if args.iter().any(|x| x == "--time") {
+            let config = BenchConfig::from_args(&args).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            });
+            Some(config)
+        } else {
+            None
+        };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+        let part = part_from_args(&args).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        });
+
+        Self {
+            format,
+            timeout,
+            bench,
+            variants: args.iter().any(|x| x == "--variants"),
+            part,
+        }
+    }
+
+    fn print(&self, report: &PartReport) {
+        match self.format {
+            OutputFormat::Text => print_report(report),
+            OutputFormat::Json => println!("{}", report.to_json_line()),
+        }
+    }
+}
+
+/// Exits with an error if `--part` selects a part that the solution does not implement.
+pub fn check_selected_part(solution: &Solution) {
+    if let Some(part) = RunArgs::from_env().part {
+        if solution.part(part).is_none() {
+            eprintln!("day {} has no part {part}.", solution.day);
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether a part of a solution binary should run. All parts run unless a single one is selected with `--part`.
+pub fn is_part_selected(part: u8) -> bool {
+    RunArgs::from_env()
+        .part
+        .is_none_or(|selected| selected == part)
+}
+
+/// Runs a part of a solution binary, then stores or submits its answer if requested.
+pub fn run_part<I, T, F>(func: F, input: I, year: Year, day: Day, part: u8) -> PartReport
+where
+    I: Clone + Send + 'static,
+    T: Display + Send + 'static,
+    F: Fn(I) -> Option<T> + Clone + Send + 'static,
+{
+    let args = RunArgs::from_env();
+
+    let show_progress = args.format == OutputFormat::Text;
+    let (result, report) = solve_part(
+        func,
+        input,
+        day,
+        part,
+        args.bench.as_ref(),
+        args.timeout,
+        show_progress,
+    );
+
+    args.print(&report);
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        store_result(&result, year, day, part);
+        submit_result(result, year, day, part);
     }
+
+    report
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+/// Runs the variants of the part of `report` in a solution binary if `--variants` is passed.
+/// Variants are benched like the part. Returns `false` if the answer of a variant differs from the answer of the part.
+pub fn run_variants(solution: &Solution, input: &'static str, report: &PartReport) -> bool {
+    let args = RunArgs::from_env();
+    let mut agree = true;
+
+    if !args.variants {
+        return agree;
+    }
+
+    for (name, func) in solution.variants(report.part) {
+        let (_, mut variant) = solve_part(
+            func,
+            input,
+            report.day,
+            report.part,
+            args.bench.as_ref(),
+            args.timeout,
+            false,
+        );
+        variant.variant = Some(name.to_string());
+
+        args.print(&variant);
+        agree &= check_variant(report, &variant);
+    }
+
+    agree
+}
+
+/// Checks that a variant has the same answer as its part, printing an error if it does not.
+/// Variants and parts that did not finish are not compared, as their failure is reported already.
+pub fn check_variant(report: &PartReport, variant: &PartReport) -> bool {
+    if report.status.is_failure() || variant.status.is_failure() || report.answer == variant.answer
+    {
+        return true;
+    }
+
+    let format = |answer: &Option<String>| answer.as_deref().unwrap_or("✖").to_string();
+    eprintln!(
+        "{}: ✖ answer {} differs from the answer of part {}: {}",
+        variant.label(),
+        format(&variant.answer),
+        report.part,
+        format(&report.answer)
+    );
+
+    false
+}
+
+/// Runs a solution part and collects its answer and timing into a [`PartReport`].
+/// The part is benched if a `bench` config is passed.
+///
+/// If the part does not return within `timeout`, it is reported as timed out.
+/// Benching only starts after the first run finished, so the timeout does not limit benching.
+///
+/// With `show_progress`, the answer is printed as soon as it is available. The final report is left to the caller.
+pub fn solve_part<I, T, F>(
+    func: F,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        let input = input.clone();
+    day: Day,
+    part: u8,
+    bench: Option<&BenchConfig>,
+    timeout: Option<Duration>,
+    show_progress: bool,
+) -> (Option<T>, PartReport)
+where
+    I: Clone + Send + 'static,
+    T: Display + Send + 'static,
+    F: Fn(I) -> Option<T> + Clone + Send + 'static,
+{
+    let part_str = format!("Part {part}");
 
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+    let timed = run_timed(func, input, bench, timeout, show_progress, |result| {
+        if show_progress {
+            print_result(result, &part_str, "");
+        }
+    });
+
+    match timed {
+        Ok(timed) => {
+            let mut report = PartReport::new(day, part, timed.result.as_ref(), timed.stats);
+            report.alloc = timed.alloc;
+            report.phases = timed.phases;
+            (timed.result, report)
+        }
+        Err((status, elapsed)) => (None, PartReport::failed(day, part, status, elapsed)),
+    }
+}
+
+/// Prints a human-readable version of a part report.
+pub fn print_report(report: &PartReport) {
+    match &report.status {
+        PartStatus::TimedOut => {
+            println!(
+                "\r{}: ✖ {ANSI_ITALIC}timed out after {:.1?}{ANSI_RESET}",
+                report.label(),
+                report.stats.average
+            );
+            return;
+        }
+        PartStatus::Panicked { message, location } => {
+            let location = location.as_deref().unwrap_or("unknown location");
+            println!(
+                "\r{}: ✖ {ANSI_ITALIC}panicked at {location}: {message}{ANSI_RESET}",
+                report.label()
+            );
+            return;
+        }
+        PartStatus::Solved | PartStatus::Unsolved => {}
+    }
+
+    print_result(
+        &report.answer,
+        &report.label(),
+        &format_duration(&report.stats.average, report.stats.samples),
+    );
+
+    if report.stats.samples > 1 {
+        println!("{}", format_stats(&report.stats));
+    }
+
+    if !report.phases.is_empty() {
+        println!("{}", format_phases(&report.phases, &report.stats.average));
+    }
+
+    if let Some(alloc) = &report.alloc {
+        println!("  {ANSI_ITALIC}{}{ANSI_RESET}", alloc.summary());
+    }
+}
+
+/// Controls how many samples are taken when benching a solution part.
+///
+/// Can be configured via the `--warmup <n>`, `--bench-time <ms>` and `--iterations <n>` arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Number of untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Approximate time budget for sampling, used to derive the iteration count.
+    pub budget: Duration,
+    /// Fixed number of samples, takes precedence over `budget` when set.
+    pub iterations: Option<u32>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            budget: Duration::from_secs(1),
+            iterations: None,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the bench configuration from the arguments passed to the process.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(warmup) = arg_value(args, "--warmup")? {
+            config.warmup = warmup;
+        }
+
+        if let Some(millis) = arg_value(args, "--bench-time")? {
+            config.budget = Duration::from_millis(millis);
+        }
+
+        config.iterations = arg_value(args, "--iterations")?;
 
-        func(input)
+        Ok(config)
+    }
+
+    /// Converts the configuration back to arguments, so it can be mirrored to child invocations.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--warmup".into(),
+            self.warmup.to_string(),
+            "--bench-time".into(),
+            self.budget.as_millis().to_string(),
+        ];
+
+        if let Some(iterations) = self.iterations {
+            args.push("--iterations".into());
+            args.push(iterations.to_string());
+        }
+
+        args
+    }
+
+    /// Number of samples to take for a solution that took `base_time` on its first run.
+    fn iterations_for(&self, base_time: &Duration) -> u32 {
+        match self.iterations {
+            Some(iterations) => iterations.max(1),
+            None => {
+                let iterations =
+                    (self.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+                // NOTE: the value is clamped to 10000 above, so the conversion can't fail.
+                u32::try_from(iterations).unwrap_or(10000)
+            }
+        }
+    }
+}
+
+/// Parses a timeout in seconds, e.g. `2.5`.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    s.parse()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("invalid timeout `{s}`, expecting a positive number of seconds."))
+}
+
+/// Parses a part number, e.g. `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part `{s}`, expecting `1` or `2`.")),
+    }
+}
+
+/// Reads the selected part from the `--part <1|2>` argument.
+fn part_from_args(args: &[String]) -> Result<Option<u8>, String> {
+    let Some(index) = args.iter().position(|x| x == "--part") else {
+        return Ok(None);
+    };
+
+    args.get(index + 1)
+        .ok_or_else(|| "expected a value for `--part`.".to_string())
+        .and_then(|x| parse_part(x))
+        .map(Some)
+}
+
+/// Reads the timeout of a part from the `--timeout <secs>` argument.
+fn timeout_from_args(args: &[String]) -> Result<Option<Duration>, String> {
+    let Some(index) = args.iter().position(|x| x == "--timeout") else {
+        return Ok(None);
+    };
+
+    args.get(index + 1)
+        .ok_or_else(|| "expected a value for `--timeout`.".to_string())
+        .and_then(|x| parse_timeout(x))
+        .map(Some)
+}
+
+fn arg_value<T: FromStr>(args: &[String], name: &str) -> Result<Option<T>, String> {
+    let Some(index) = args.iter().position(|x| x == name) else {
+        return Ok(None);
     };
-    let base_time = timer.elapsed();
+
+    args.get(index + 1)
+        .and_then(|x| x.parse().ok())
+        .map(Some)
+        .ok_or_else(|| format!("expected a numeric value for `{name}`."))
+}
+
+/// Summary statistics over the samples taken for a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub average: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl BenchStats {
+    /// Computes statistics for a set of samples. Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let average = average_duration(&sorted);
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = sorted
+            .iter()
+            .map(|x| (x.as_nanos() as f64 - average as f64).powi(2))
+            .sum::<f64>()
+            / sorted.len() as f64;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let std_dev = Duration::from_nanos(variance.sqrt() as u64);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let average = Duration::from_nanos(average as u64);
+
+        Some(Self {
+            samples: sorted.len() as u128,
+            average,
+            min: sorted[0],
+            median: percentile(&sorted, 50),
+            p95: percentile(&sorted, 95),
+            std_dev,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (percent * sorted.len()).div_ceil(100);
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Run a solution part. The behavior differs depending on whether a bench config is passed:
+///  1. without a config, the function is executed once.
+///  2. with a config, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     The time budget, warm-up and iteration count can be changed via [`BenchConfig`].
+///
+/// Allocations are counted during the first execution if counting is enabled, see [`heap::measure`].
+/// Phases marked with [`span::enter`] are taken from the first execution or averaged over the bench samples.
+/// Parts are neither benched nor measured once a part timed out, see [`has_detached_part`].
+///
+/// Returns the status and elapsed time if the first execution panicked or did not finish within `timeout`.
+fn run_timed<I, T, F>(
+    func: F,
+    input: I,
+    bench_config: Option<&BenchConfig>,
+    timeout: Option<Duration>,
+    show_progress: bool,
+    hook: impl Fn(&T),
+) -> Result<Timed<T>, (PartStatus, Duration)>
+where
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+    F: Fn(I) -> T + Clone + Send + 'static,
+{
+    let first = match timeout {
+        Some(timeout) => run_once_with_timeout(func.clone(), input.clone(), timeout),
+        None => run_once(&func, input.clone()),
+    };
+
+    let result = first.result.map_err(|status| (status, first.elapsed))?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let (stats, phases) = match bench_config.filter(|_| !has_detached_part()) {
+        Some(config) => bench(func, input, &first.elapsed, config, show_progress),
+        None => (
+            BenchStats::from_samples(&[first.elapsed]).unwrap(),
+            first.phases,
+        ),
     };
 
-    (result, run.0, run.1)
+    Ok(Timed {
+        result,
+        stats,
+        alloc: first.alloc,
+        phases,
+    })
+}
+
+/// Answer and measurements of a part that finished.
+struct Timed<T> {
+    result: T,
+    stats: BenchStats,
+    alloc: Option<AllocStats>,
+    phases: Vec<PhaseTiming>,
+}
+
+/// Outcome of a single run of a solution part.
+struct RunOnce<T> {
+    result: Result<T, PartStatus>,
+    elapsed: Duration,
+    alloc: Option<AllocStats>,
+    phases: Vec<PhaseTiming>,
+}
+
+/// Runs a solution part once. A panic of the part is returned as [`PartStatus::Panicked`].
+fn run_once<I, T>(func: impl Fn(I) -> T, input: I) -> RunOnce<T> {
+    let timer = Instant::now();
+    let result = catch_panic(|| {
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
+
+        // NOTE: spans are recorded outside of the measurement, so they don't count towards the allocations.
+        span::record(
+            || {
+                if has_detached_part() {
+                    (func(input), None)
+                } else {
+                    heap::measure(|| func(input))
+                }
+            },
+            1,
+        )
+    });
+    let elapsed = timer.elapsed();
+
+    match result {
+        Ok(((result, alloc), phases)) => RunOnce {
+            result: Ok(result),
+            elapsed,
+            alloc,
+            phases,
+        },
+        Err(status) => RunOnce {
+            result: Err(status),
+            elapsed,
+            alloc: None,
+            phases: vec![],
+        },
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Runs a solution part once without measuring it, e.g. for examples.
+/// A panic or a run longer than `timeout` is returned as its status.
+pub fn run_part_once<I, T, F>(func: F, input: I, timeout: Option<Duration>) -> Result<T, PartStatus>
+where
+    I: Send + 'static,
+    T: Send + 'static,
+    F: Fn(I) -> T + Send + 'static,
+{
+    match timeout {
+        Some(timeout) => run_once_with_timeout(func, input, timeout).result,
+        None => run_once(func, input).result,
+    }
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Runs a solution part on a separate thread and waits for at most `timeout`.
+///
+/// NOTE: threads can't be cancelled, a part that timed out keeps running in the background until the process exits.
+/// Later parts are not benched or measured then, see [`has_detached_part`].
+fn run_once_with_timeout<I, T, F>(func: F, input: I, timeout: Duration) -> RunOnce<T>
+where
+    I: Send + 'static,
+    T: Send + 'static,
+    F: Fn(I) -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let handle = thread::Builder::new()
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(run_once(func, input));
+        })
+        .expect("failed to spawn thread for solution part");
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            DETACHED_PART.store(true, Ordering::Relaxed);
+            RunOnce {
+                result: Err(PartStatus::TimedOut),
+                elapsed: timeout,
+                alloc: None,
+                phases: vec![],
+            }
+        }
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("solution thread exited without sending a result"),
+        },
+    }
+}
+
+thread_local! {
+    /// Whether panics on this thread are caught by [`catch_panic`].
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    /// Message and location of the last panic caught on this thread.
+    static CAUGHT_PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+}
 
-    let mut timers: Vec<Duration> = vec![];
+/// Runs `func`, converting a panic into [`PartStatus::Panicked`] with its message and location.
+///
+/// While a panic is caught, the default panic message is not printed. The status is reported instead.
+pub fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, PartStatus> {
+    static INSTALL_HOOK: Once = Once::new();
 
-    for _ in 0..bench_iterations {
-        // need a clone here to make the borrow checker happy.
-        let cloned = input.clone();
-        let timer = Instant::now();
-        black_box(func(black_box(cloned)));
-        timers.push(timer.elapsed());
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANIC.get() {
+                let location = info.location().map(ToString::to_string);
+                CAUGHT_PANIC.set(Some((panic_message(info.payload()), location)));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING_PANIC.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING_PANIC.set(was_catching);
+
+    result.map_err(|payload| {
+        let (message, location) = CAUGHT_PANIC
+            .take()
+            .unwrap_or_else(|| (panic_message(payload.as_ref()), None));
+        PartStatus::Panicked { message, location }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
     }
+}
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    show_progress: bool,
+) -> (BenchStats, Vec<PhaseTiming>) {
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    for _ in 0..config.warmup {
+        black_box(func(black_box(input.clone())));
+    }
+
+    let bench_iterations = config.iterations_for(base_time);
+
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
+
+    let ((), phases) = span::record(
+        || {
+            for _ in 0..bench_iterations {
+                // need a clone here to make the borrow checker happy.
+                let cloned = input.clone();
+                let timer = Instant::now();
+                black_box(func(black_box(cloned)));
+                timers.push(timer.elapsed());
+            }
+        },
         bench_iterations,
-    )
+    );
+
+    (BenchStats::from_samples(&timers).unwrap(), phases)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -94,6 +724,33 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
+fn format_stats(stats: &BenchStats) -> String {
+    format!(
+        "  {ANSI_ITALIC}min {:.1?} · median {:.1?} · p95 {:.1?} · σ {:.1?}{ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.std_dev
+    )
+}
+
+/// Formats the phases of a part with their share of the part's duration, e.g. `parse 1.2ms (35%) · solve 2.2ms (65%)`.
+fn format_phases(phases: &[PhaseTiming], total: &Duration) -> String {
+    let phases = phases
+        .iter()
+        .map(|phase| {
+            #[allow(clippy::cast_precision_loss)]
+            let share = phase.duration.as_nanos() as f64 / total.as_nanos().max(1) as f64;
+            format!(
+                "{} {:.1?} ({:.0}%)",
+                phase.name,
+                phase.duration,
+                share * 100.0
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" · ");
+
+    format!("  {ANSI_ITALIC}{phases}{ANSI_RESET}")
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -129,14 +786,48 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Store the result of a part in the answer ledger if `--store` was passed to `solve`.
+fn store_result<T: Display>(result: &T, year: Year, day: Day, part: u8) {
+    if !env::args().any(|x| x == "--store") {
+        return;
+    }
+
+    let mut answers = match Answers::read_from_file(year, day) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read stored answers: {e}");
+            return;
+        }
+    };
+
+    let answer = result.to_string();
+
+    if answers.get(part) == Some(&answer) {
+        return;
+    }
+
+    if let Some(previous) = answers.get(part) {
+        println!("Replacing stored answer for part {part}: {previous}");
+    }
+
+    answers.set(part, answer);
+
+    match answers.store_file(year, day) {
+        Ok(()) => println!("Stored answer for part {part}."),
+        Err(e) => eprintln!("Failed to store answer: {e}"),
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session token is configured.
+///  3. the submission ledger does not rule out the answer.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmissionOutcome, AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -159,11 +850,119 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to create Advent of Code client: {e}");
+        process::exit(1);
+    });
+
+    let answer = result.to_string();
+
+    let mut submissions = Submissions::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("failed to read submission ledger: {e}");
+        process::exit(1);
+    });
+
+    if let Err(refusal) = submissions.check(year, day, part, &answer, submissions::now()) {
+        eprintln!("Refusing to submit {answer}: {refusal}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let outcome = client.submit(day, part, &answer);
+
+    match &outcome {
+        Ok(outcome) => {
+            println!("{outcome}");
+            submissions.push(Submission::new(year, day, part, &answer, outcome.clone()));
+            if let Err(e) = submissions.store_file() {
+                eprintln!("failed to store submission ledger: {e}");
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
+    }
+
+    Some(outcome)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{catch_panic, BenchConfig, BenchStats};
+    use crate::template::report::PartStatus;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_millis(*x)).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_samples() {
+        let stats = BenchStats::from_samples(&millis(&[4, 2, 8, 6, 10, 2, 4, 4, 4, 6])).unwrap();
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.average, Duration::from_millis(5));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(4));
+        assert_eq!(stats.p95, Duration::from_millis(10));
+        assert_eq!(stats.std_dev, Duration::from_nanos(2_408_318));
+    }
+
+    #[test]
+    fn computes_stats_for_single_sample() {
+        let stats = BenchStats::from_samples(&millis(&[3])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.min, stats.p95);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn parses_bench_config() {
+        let args: Vec<String> = ["01", "--time", "--warmup", "5", "--bench-time", "200"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let config = BenchConfig::from_args(&args).unwrap();
+        assert_eq!(config.warmup, 5);
+        assert_eq!(config.budget, Duration::from_millis(200));
+        assert_eq!(config.iterations, None);
+        assert_eq!(BenchConfig::from_args(&config.to_args()).unwrap(), config);
+    }
+
+    #[test]
+    fn errors_for_invalid_bench_config() {
+        let args = vec!["--iterations".to_string(), "many".to_string()];
+        assert!(BenchConfig::from_args(&args).is_err());
+    }
+
+    #[test]
+    fn derives_iterations_from_budget() {
+        let config = BenchConfig::default();
+        assert_eq!(config.iterations_for(&Duration::from_millis(1)), 1000);
+        assert_eq!(config.iterations_for(&Duration::from_secs(2)), 10);
+        assert_eq!(config.iterations_for(&Duration::from_nanos(1)), 10000);
+
+        let fixed = BenchConfig {
+            iterations: Some(42),
+            ..BenchConfig::default()
+        };
+        assert_eq!(fixed.iterations_for(&Duration::from_secs(2)), 42);
+    }
+
+    #[test]
+    fn catches_panics_with_location() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let Err(PartStatus::Panicked { message, location }) =
+            catch_panic(|| -> u32 { panic!("Expected pair") })
+        else {
+            panic!("expected a panicked status");
+        };
+        assert_eq!(message, "Expected pair");
+        assert!(location.unwrap().starts_with("src/template/runner.rs:"));
+    }
 }
@@ -0,0 +1,176 @@
+/// Lightweight spans that solutions use to mark phases of a part, e.g. parsing and solving.
+/// Spans are only recorded while the runner measures a part. Otherwise, they only cost a timer read.
+///
+/// ```ignore
+/// use advent_of_code::template::span;
+///
+/// pub fn part_one(input: &str) -> Option<u32> {
+///     let parse = span::enter("parse");
+///     let grid = parse_grid(input);
+///     parse.end();
+///
+///     let _solve = span::enter("solve");
+///     Some(count_paths(&grid))
+/// }
+///
+/// // the same phases, with the parse and solve spans handled by the helper.
+/// pub fn part_two(input: &str) -> Option<u32> {
+///     let (grid, _solve) = span::parse_then_solve(|| parse_grid(input));
+///     Some(count_loops(&grid))
+/// }
+/// ```
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    /// Summed up durations of the spans that ended while recording on this thread, by phase.
+    static RECORDED: RefCell<Option<Vec<(&'static str, Duration)>>> = const { RefCell::new(None) };
+}
+
+/// Scope guard that records the time until it is dropped or ended.
+#[must_use = "the span ends when it is dropped"]
+pub struct Span {
+    name: &'static str,
+    start: Instant,
+}
+
+/// Starts a span for the phase `name`.
+pub fn enter(name: &'static str) -> Span {
+    Span {
+        name,
+        start: Instant::now(),
+    }
+}
+
+/// Runs `parse` in a `parse` span, then starts the `solve` span.
+/// The solve span lasts until the returned guard is dropped, usually at the end of the part.
+pub fn parse_then_solve<T>(parse: impl FnOnce() -> T) -> (T, Span) {
+    let span = enter("parse");
+    let parsed = parse();
+    span.end();
+    (parsed, enter("solve"))
+}
+
+impl Span {
+    /// Ends the span before the end of its scope.
+    pub fn end(self) {}
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        RECORDED.with_borrow_mut(|recorded| {
+            if let Some(recorded) = recorded {
+                match recorded.iter_mut().find(|(name, _)| *name == self.name) {
+                    Some((_, duration)) => *duration += elapsed,
+                    None => recorded.push((self.name, elapsed)),
+                }
+            }
+        });
+    }
+}
+
+/// Time spent in a phase of a part, averaged over all measured runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhaseTiming {
+    pub name: String,
+    pub duration: Duration,
+}
+
+/// Runs `func` and collects the spans it entered on the current thread.
+/// Spans of the same phase are summed up and divided by `runs`, phases are in order of their first occurrence.
+///
+/// NOTE: spans entered on other threads, e.g. in a thread pool, are not recorded.
+pub fn record<T>(func: impl FnOnce() -> T, runs: u32) -> (T, Vec<PhaseTiming>) {
+    // NOTE: reserved up front, so recording the phases does not allocate while `func` runs.
+    let outer = RECORDED.replace(Some(Vec::with_capacity(16)));
+    let result = func();
+    let recorded = RECORDED.replace(outer).unwrap_or_default();
+
+    let phases = recorded
+        .into_iter()
+        .map(|(name, duration)| PhaseTiming {
+            name: name.to_string(),
+            duration: duration / runs.max(1),
+        })
+        .collect();
+
+    (result, phases)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use super::{enter, parse_then_solve, record};
+
+    #[test]
+    fn records_phases_in_order() {
+        let (answer, phases) = record(
+            || {
+                let parse = enter("parse");
+                thread::sleep(Duration::from_millis(2));
+                parse.end();
+
+                let _solve = enter("solve");
+                42
+            },
+            1,
+        );
+
+        assert_eq!(answer, 42);
+        let names: Vec<&str> = phases.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["parse", "solve"]);
+        assert!(phases[0].duration >= Duration::from_millis(2));
+    }
+
+    #[test]
+    fn records_parse_then_solve() {
+        let (answer, phases) = record(
+            || {
+                let (parsed, _solve) = parse_then_solve(|| 21);
+                parsed * 2
+            },
+            1,
+        );
+
+        assert_eq!(answer, 42);
+        let names: Vec<&str> = phases.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["parse", "solve"]);
+    }
+
+    #[test]
+    fn averages_phases_over_runs() {
+        let start = Instant::now();
+        let (_, phases) = record(
+            || {
+                for _ in 0..4 {
+                    let _parse = enter("parse");
+                    thread::sleep(Duration::from_millis(1));
+                }
+            },
+            4,
+        );
+        let total = start.elapsed();
+
+        // four spans of the same phase are summed up into one phase, then divided by the runs.
+        assert_eq!(phases.len(), 1);
+        assert_eq!(phases[0].name, "parse");
+        assert!(phases[0].duration >= Duration::from_millis(1));
+        assert!(phases[0].duration <= total / 4);
+    }
+
+    #[test]
+    fn ignores_spans_outside_of_recording() {
+        drop(enter("parse"));
+        let (_, phases) = record(|| {}, 1);
+        assert!(phases.is_empty());
+    }
+}
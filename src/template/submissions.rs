@@ -0,0 +1,499 @@
+/// Ledger of answers submitted to Advent of Code, stored in `data/submissions.json`.
+/// Used to refuse submissions that are known to be wrong before they reach the server.
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{aoc_client::SubmissionOutcome, Day, Year};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// Advent of Code asks to wait a minute before answering again after a wrong answer.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// A single submitted answer and the verdict of the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Submission {
+    /// Creates a submission for the current time.
+    pub fn new(year: Year, day: Day, part: u8, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: now(),
+        }
+    }
+}
+
+/// All submissions, from oldest to newest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+/// Reason for refusing to submit an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with the contained answer.
+    AlreadySolved(String),
+    /// The exact answer was rejected before.
+    AlreadyRejected,
+    /// A previous answer that is lower or equal was too high.
+    TooHigh { bound: String },
+    /// A previous answer that is higher or equal was too low.
+    TooLow { bound: String },
+    /// The server asked to wait before submitting again.
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "This part was already solved with {answer}.")
+            }
+            Refusal::AlreadyRejected => write!(f, "This answer was already rejected."),
+            Refusal::TooHigh { bound } => {
+                write!(f, "This answer is too high, {bound} was already too high.")
+            }
+            Refusal::TooLow { bound } => {
+                write!(f, "This answer is too low, {bound} was already too low.")
+            }
+            Refusal::Cooldown(wait) => {
+                write!(f, "An answer was given too recently, wait {wait:?}.")
+            }
+        }
+    }
+}
+
+impl Submissions {
+    /// Rehydrate the ledger from its JSON file. If not present, returns an empty ledger.
+    /// Submissions stored before the ledger recorded years are assigned to `year`.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(SUBMISSIONS_FILE_PATH) {
+            Ok(s) => Submissions::parse(&s, year),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Dehydrate the ledger to its JSON file.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.data.push(submission);
+    }
+
+    /// Checks whether an answer can be submitted at `timestamp` given the previous verdicts.
+    pub fn check(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+        timestamp: u64,
+    ) -> Result<(), Refusal> {
+        // the rate limit applies to the whole account, not just the current puzzle.
+        if let Some(wait) = self.cooldown(timestamp) {
+            return Err(Refusal::Cooldown(wait));
+        }
+
+        let previous = self
+            .data
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part);
+
+        for submission in previous {
+            let same_answer = submission.answer == answer;
+
+            match &submission.outcome {
+                SubmissionOutcome::Correct => {
+                    return Err(Refusal::AlreadySolved(submission.answer.clone()))
+                }
+                SubmissionOutcome::Incorrect
+                | SubmissionOutcome::TooHigh
+                | SubmissionOutcome::TooLow
+                    if same_answer =>
+                {
+                    return Err(Refusal::AlreadyRejected)
+                }
+                SubmissionOutcome::TooHigh
+                    if compare(answer, &submission.answer).is_some_and(Ordering::is_ge) =>
+                {
+                    return Err(Refusal::TooHigh {
+                        bound: submission.answer.clone(),
+                    });
+                }
+                SubmissionOutcome::TooLow
+                    if compare(answer, &submission.answer).is_some_and(Ordering::is_le) =>
+                {
+                    return Err(Refusal::TooLow {
+                        bound: submission.answer.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the time left to wait if the latest submission was wrong or rate limited.
+    fn cooldown(&self, timestamp: u64) -> Option<Duration> {
+        let last = self.data.last()?;
+
+        let wait = match last.outcome {
+            SubmissionOutcome::RateLimited(Some(wait)) => wait,
+            SubmissionOutcome::Incorrect
+            | SubmissionOutcome::TooHigh
+            | SubmissionOutcome::TooLow => WRONG_ANSWER_WAIT,
+            _ => return None,
+        };
+
+        let until = last.timestamp + wait.as_secs();
+        (until > timestamp).then(|| Duration::from_secs(until - timestamp))
+    }
+}
+
+/// Compares two answers numerically. Answers that are not integers can't be ordered.
+fn compare(a: &str, b: &str) -> Option<Ordering> {
+    Some(a.parse::<i128>().ok()?.cmp(&b.parse::<i128>().ok()?))
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Submissions {
+    /// Parses the JSON ledger. Submissions without a year are assigned to `year`.
+    fn parse(value: &str, year: Year) -> Result<Self, String> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let data = json
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected submissions.data to be an array.")?;
+
+        Ok(Submissions {
+            data: data
+                .iter()
+                .map(|submission| Submission::parse(submission, year))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(value.year.into_inner().into()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::Number(value.day.into_inner().into()),
+        );
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+
+        let verdict = match &value.outcome {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::Incorrect => "incorrect",
+            SubmissionOutcome::TooHigh => "too_high",
+            SubmissionOutcome::TooLow => "too_low",
+            SubmissionOutcome::RateLimited(_) => "rate_limited",
+            SubmissionOutcome::AlreadySolved => "already_solved",
+            SubmissionOutcome::Unknown(_) => "unknown",
+        };
+        map.insert("verdict".into(), JsonValue::String(verdict.into()));
+
+        if let SubmissionOutcome::RateLimited(Some(wait)) = value.outcome {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("wait_secs".into(), JsonValue::Number(wait.as_secs() as f64));
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Submission {
+    /// Parses a submission, a missing year defaults to `default_year`.
+    fn parse(value: &JsonValue, default_year: Year) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let year = match json.get("year") {
+            None => default_year,
+            Some(v) => v
+                .get::<f64>()
+                .and_then(|v| Year::new(*v as u16))
+                .ok_or("Expected submission.year to be a valid year.")?,
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|v| Day::new(*v as u8))
+            .ok_or("Expected submission.day to be a valid day.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let wait = json
+            .get("wait_secs")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| Duration::from_secs(*v as u64));
+
+        let outcome = match json.get("verdict").and_then(|v| v.get::<String>()) {
+            Some(v) if v == "correct" => SubmissionOutcome::Correct,
+            Some(v) if v == "incorrect" => SubmissionOutcome::Incorrect,
+            Some(v) if v == "too_high" => SubmissionOutcome::TooHigh,
+            Some(v) if v == "too_low" => SubmissionOutcome::TooLow,
+            Some(v) if v == "rate_limited" => SubmissionOutcome::RateLimited(wait),
+            Some(v) if v == "already_solved" => SubmissionOutcome::AlreadySolved,
+            Some(v) if v == "unknown" => SubmissionOutcome::Unknown(String::new()),
+            _ => return Err("Expected submission.verdict to be a known verdict.".into()),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        Ok(Submission {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+            timestamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Refusal, Submission, Submissions};
+    use crate::template::aoc_client::SubmissionOutcome;
+    use crate::{day, year};
+
+    fn submission(
+        part: u8,
+        answer: &str,
+        outcome: SubmissionOutcome,
+        timestamp: u64,
+    ) -> Submission {
+        Submission {
+            year: year!(2024),
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = Submissions {
+            data: vec![
+                submission(1, "42", SubmissionOutcome::TooLow, 100),
+                submission(
+                    1,
+                    "50",
+                    SubmissionOutcome::RateLimited(Some(Duration::from_secs(30))),
+                    110,
+                ),
+                submission(1, "50", SubmissionOutcome::Correct, 200),
+            ],
+        };
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::parse(&json, year!(2023)).unwrap(), submissions);
+    }
+
+    #[test]
+    fn defaults_missing_year() {
+        let json = r#"{"data": [{"day": 1, "part": 1, "answer": "42", "verdict": "correct", "timestamp": 0}]}"#;
+        let submissions = Submissions::parse(json, year!(2024)).unwrap();
+        assert_eq!(
+            submissions.data,
+            vec![submission(1, "42", SubmissionOutcome::Correct, 0)]
+        );
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let submissions = Submissions {
+            data: vec![submission(1, "abc", SubmissionOutcome::Incorrect, 0)],
+        };
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "abc", 100),
+            Err(Refusal::AlreadyRejected)
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "abd", 100),
+            Ok(())
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 2, "abc", 100),
+            Ok(())
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(2), 1, "abc", 100),
+            Ok(())
+        );
+        assert_eq!(
+            submissions.check(year!(2023), day!(1), 1, "abc", 100),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let submissions = Submissions {
+            data: vec![
+                submission(1, "100", SubmissionOutcome::TooHigh, 0),
+                submission(1, "10", SubmissionOutcome::TooLow, 0),
+            ],
+        };
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "150", 100),
+            Err(Refusal::TooHigh {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "-5", 100),
+            Err(Refusal::TooLow { bound: "10".into() })
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "50", 100),
+            Ok(())
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "fifty", 100),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let submissions = Submissions {
+            data: vec![submission(
+                2,
+                "1",
+                SubmissionOutcome::RateLimited(Some(Duration::from_secs(60))),
+                1000,
+            )],
+        };
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "1", 1020),
+            Err(Refusal::Cooldown(Duration::from_secs(40)))
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "1", 1060),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn waits_after_wrong_answers() {
+        let submissions = Submissions {
+            data: vec![submission(1, "abc", SubmissionOutcome::Incorrect, 1000)],
+        };
+        assert_eq!(
+            submissions.check(year!(2024), day!(2), 1, "1", 1015),
+            Err(Refusal::Cooldown(Duration::from_secs(45)))
+        );
+        assert_eq!(
+            submissions.check(year!(2024), day!(2), 1, "1", 1060),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let submissions = Submissions {
+            data: vec![submission(1, "7", SubmissionOutcome::Correct, 0)],
+        };
+        assert_eq!(
+            submissions.check(year!(2024), day!(1), 1, "8", 100),
+            Err(Refusal::AlreadySolved("7".into()))
+        );
+    }
+}
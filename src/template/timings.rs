@@ -1,37 +1,257 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{
+    data_dir,
+    environment::Environment,
+    git,
+    heap::AllocStats,
+    registry,
+    report::{part_from_json, PartReport, PartStatus},
+    Day, Year,
+};
 
 /// Represents benchmark times for a single day.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    /// Results of the parts that were solved or did not finish, ordered by part.
+    pub parts: Vec<PartTiming>,
+}
+
+/// The benchmark result of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    pub part: u8,
+    /// Average duration in nanoseconds. For parts that did not finish, the time until they were stopped.
+    pub nanos: f64,
+    /// Number of measured runs. Not known for timings migrated from v1.
+    pub samples: Option<u64>,
+    /// Either solved, or the reason the part did not finish, e.g. because it timed out or panicked.
+    pub status: PartStatus,
+    /// Heap usage of the part, if allocations were counted with `--alloc`.
+    pub alloc: Option<AllocStats>,
 }
 
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
+    /// The latest timings of every day, used for the readme.
+    pub data: Vec<Timing>,
+    /// Previous runs, from oldest to newest.
+    pub history: Vec<TimingRun>,
+}
+
+/// Represents the benchmark times of a single `cargo time` run.
+#[derive(Clone, Debug)]
+pub struct TimingRun {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The git commit that was checked out during the run.
+    pub commit: Option<String>,
+    /// The machine and toolchain of the run. Not known for runs stored before environments were recorded.
+    pub environment: Option<Environment>,
     pub data: Vec<Timing>,
 }
 
+impl TimingRun {
+    /// Creates a run for the current time and git commit, recorded in `environment`.
+    pub fn new(data: Vec<Timing>, environment: Environment) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            timestamp,
+            commit: git::head_commit(),
+            environment: Some(environment),
+            data,
+        }
+    }
+
+    /// Whether the run was recorded on `commit`. Either hash may be abbreviated.
+    pub fn is_at_commit(&self, commit: &str) -> bool {
+        self.commit
+            .as_ref()
+            .is_some_and(|c| c.starts_with(commit) || commit.starts_with(c.as_str()))
+    }
+}
+
+impl Timing {
+    /// Collects the timings of solved parts and the failures of parts that timed out or panicked from the reports of a day.
+    pub fn from_reports(reports: &[PartReport], day: Day) -> Self {
+        let mut parts: Vec<PartTiming> = reports
+            .iter()
+            .filter(|report| {
+                report.day == day
+                    && (report.status == PartStatus::Solved || report.status.is_failure())
+            })
+            .map(|report| {
+                #[allow(clippy::cast_precision_loss)]
+                let nanos = report.stats.average.as_nanos() as f64;
+
+                PartTiming {
+                    part: report.part,
+                    nanos,
+                    samples: u64::try_from(report.stats.samples).ok(),
+                    status: report.status.clone(),
+                    alloc: report.alloc.filter(|_| report.status == PartStatus::Solved),
+                }
+            })
+            .collect();
+
+        parts.sort_by_key(|p| p.part);
+        Timing { day, parts }
+    }
+
+    /// Returns the result of a part, if it was solved or did not finish.
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    /// Returns the duration of a part in nanoseconds, if it was solved.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part)
+            .filter(|p| p.status == PartStatus::Solved)
+            .map(|p| p.nanos)
+    }
+
+    /// Returns the status of a part, if it did not finish.
+    pub fn failure(&self, part: u8) -> Option<&PartStatus> {
+        self.part(part)
+            .map(|p| &p.status)
+            .filter(|status| status.is_failure())
+    }
+
+    /// Returns the heap usage of a part, if allocations were counted.
+    pub fn allocations(&self, part: u8) -> Option<&AllocStats> {
+        self.part(part).and_then(|p| p.alloc.as_ref())
+    }
+
+    /// Sum of the durations of the solved parts in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        [1, 2]
+            .into_iter()
+            .filter_map(|part| self.part_nanos(part))
+            .sum()
+    }
+}
+
+/// How the results of a new run replace the stored results of a part.
+/// With every policy, parts that were not measured keep their stored result and failures do not replace a stored duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// The result of the new run replaces the stored one.
+    #[default]
+    Latest,
+    /// The faster of both durations is kept.
+    Best,
+    /// The results of a day are only replaced if the new run solved both parts.
+    Complete,
+}
+
+impl FromStr for MergePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(Self::Latest),
+            "best" => Ok(Self::Best),
+            "complete" => Ok(Self::Complete),
+            x => Err(format!(
+                "unknown merge policy `{x}`, expecting `latest`, `best` or `complete`."
+            )),
+        }
+    }
+}
+
+impl Timing {
+    /// Merges the results of a new run of the same day into `self`, see [`MergePolicy`].
+    /// `implemented` are the parts the day has, a run is complete if all of them are solved.
+    fn merge(&self, new: &Timing, policy: MergePolicy, implemented: &[u8]) -> Timing {
+        // NOTE: days that have no stored results take partial results, there is nothing to lose.
+        let is_complete = implemented
+            .iter()
+            .all(|part| new.part_nanos(*part).is_some());
+        if policy == MergePolicy::Complete && !is_complete && !self.parts.is_empty() {
+            return self.clone();
+        }
+
+        let parts = [1, 2]
+            .into_iter()
+            .filter_map(|part| match (self.part(part), new.part(part)) {
+                (stored, None) => stored.cloned(),
+                (None, Some(new)) => Some(new.clone()),
+                (Some(stored), Some(new)) => Some(PartTiming::merge(stored, new, policy).clone()),
+            })
+            .collect();
+
+        Timing {
+            day: self.day,
+            parts,
+        }
+    }
+}
+
+impl PartTiming {
+    /// Chooses between the stored and the new result of a part.
+    fn merge<'a>(stored: &'a Self, new: &'a Self, policy: MergePolicy) -> &'a Self {
+        let stored_solved = stored.status == PartStatus::Solved;
+        let new_solved = new.status == PartStatus::Solved;
+
+        match policy {
+            _ if stored_solved && !new_solved => stored,
+            MergePolicy::Best if stored_solved && stored.nanos <= new.nanos => stored,
+            _ => new,
+        }
+    }
+}
+
+/// Formats a duration in nanoseconds the way it is shown in the readme, e.g. `589.2ms`.
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = std::time::Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parses a duration formatted with `{:?}` into nanoseconds. Used to migrate v1 timings.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s.trim() {
+        s if s.ends_with("ns") => parse_to_float(s, "ns"),
+        s if s.ends_with("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.ends_with("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let path = get_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        let s = fs::read_to_string(get_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -44,47 +264,83 @@ impl Timings {
         }
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
-    pub fn merge(&self, new: &Self) -> Self {
-        let mut data: Vec<Timing> = vec![];
+    /// Merge the timings of a new run into `self`, part by part according to `policy`.
+    /// Parts that were not measured in `new` keep their stored results.
+    pub fn merge(&self, new: &Self, policy: MergePolicy, year: Year) -> Self {
+        let mut data = self.data.clone();
 
         for timing in &new.data {
-            data.push(timing.clone());
-        }
+            // days that are not compiled into the library are assumed to have both parts.
+            let implemented: Vec<u8> = registry::get(year, timing.day).map_or(vec![1, 2], |s| {
+                s.parts.iter().map(|(part, _)| *part).collect()
+            });
 
-        for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
-                data.push(timing.clone());
+            match data.iter_mut().find(|t| t.day == timing.day) {
+                Some(stored) => *stored = stored.merge(timing, policy, &implemented),
+                None => data.push(timing.clone()),
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+        Timings {
+            data,
+            history: self.history.clone(),
+        }
+    }
+
+    /// Finds the latest run recorded on `commit`.
+    pub fn find_run(&self, commit: &str) -> Option<&TimingRun> {
+        self.history
+            .iter()
+            .rev()
+            .find(|run| run.is_at_commit(commit))
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
+        self.is_part_benched(day, 1) && self.is_part_benched(day, 2)
+    }
+
+    /// Whether a part of a day has a stored time. Failed parts have no time.
+    pub fn is_part_benched(&self, day: Day, part: u8) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.part_nanos(part).is_some())
     }
 }
 
+/// The version of the timings format that is written.
+///  - v1 stored the formatted duration of each part, e.g. `"589.2ms"`, and the sum of both parts.
+///  - v2 stores nanoseconds, sample count and status per part.
+const TIMINGS_VERSION: u8 = 2;
+
+/// Timings are stored per year, e.g. in `data/2024/timings.json`.
+fn get_path(year: Year) -> PathBuf {
+    data_dir(year).join("timings.json")
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("version".into(), JsonValue::Number(TIMINGS_VERSION.into()));
+
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -95,18 +351,47 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: v1 files do not have a version and are migrated when read.
+        let version = match json.get("version") {
+            Some(version) => version
+                .get::<f64>()
+                .filter(|v| **v == f64::from(TIMINGS_VERSION))
+                .map(|_| TIMINGS_VERSION)
+                .ok_or(format!(
+                    "expected `json.version` to be {TIMINGS_VERSION}, the only supported version."
+                ))?,
+            None => 1,
+        };
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // NOTE: v1 files were written before runs were tracked and do not have a history.
+        let json_history = match version {
+            1 => &[],
+            _ => json
+                .get("history")
+                .ok_or("expected JSON document to have key `history`.")?
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|x| Timing::from_json(x, version))
+                .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(|x| TimingRun::from_json(x, version))
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -114,40 +399,102 @@ impl TryFrom<String> for Timings {
 
 /* -------------------------------------------------------------------------- */
 
-impl From<&Timing> for JsonValue {
-    fn from(value: &Timing) -> Self {
+impl From<&TimingRun> for JsonValue {
+    fn from(value: &TimingRun) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
 
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
                 None => JsonValue::Null,
             },
         );
 
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
+            "environment".into(),
+            match &value.environment {
+                Some(x) => JsonValue::from(x),
                 None => JsonValue::Null,
             },
         );
 
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
 
-impl TryFrom<&JsonValue> for Timing {
-    type Error = String;
+impl TimingRun {
+    /// Rehydrate a run from a JSON value written in the timings format `version`.
+    fn from_json(value: &JsonValue, version: u8) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
 
-    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected run.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected run.commit to be null or string.")?;
+
+        // NOTE: runs stored before environments were recorded do not have the key.
+        let environment = match json.get("environment") {
+            Some(v) if !v.is_null() => Some(Environment::try_from(v)?),
+            _ => None,
+        };
+
+        let data = json
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected run.data to be an array.")?;
+
+        Ok(TimingRun {
+            timestamp,
+            commit: commit.cloned(),
+            environment,
+            data: data
+                .iter()
+                .map(|x| Timing::from_json(x, version))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Timing> for JsonValue {
+    fn from(value: &Timing) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "parts".into(),
+            JsonValue::Array(value.parts.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl Timing {
+    /// Rehydrate the timing of a day from a JSON value written in the timings format `version`.
+    fn from_json(value: &JsonValue, version: u8) -> Result<Self, String> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
@@ -158,26 +505,110 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        if version == 1 {
+            return Timing::from_json_v1(json, day);
+        }
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let mut parts: Vec<PartTiming> = json
+            .get("parts")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected timing.parts to be an array.")?
+            .iter()
+            .map(PartTiming::try_from)
+            .collect::<Result<_, _>>()?;
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
+        parts.sort_by_key(|p| p.part);
+        Ok(Timing { day, parts })
+    }
+
+    /// Migrates a v1 timing, which stored the formatted durations of solved parts.
+    fn from_json_v1(json: &HashMap<String, JsonValue>, day: Day) -> Result<Self, String> {
+        let mut parts = vec![];
+
+        for part in [1, 2] {
+            let key = format!("part_{part}");
+            let formatted = json
+                .get(&key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected timing.{key} to be null or string."))?;
+
+            if let Some(nanos) = formatted.and_then(|x| parse_duration(x)) {
+                parts.push(PartTiming {
+                    part,
+                    nanos,
+                    samples: None,
+                    status: PartStatus::Solved,
+                    alloc: None,
+                });
+            }
+        }
+
+        json.get("total_nanos")
+            .and_then(|v| v.get::<f64>())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+        Ok(Timing { day, parts })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            match value.samples {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        value.status.insert_json(&mut map);
+
+        if let Some(alloc) = &value.alloc {
+            alloc.insert_json(&mut map);
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = match json.get("samples") {
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|x| *x as u64)
+                    .ok_or("Expected part.samples to be null or a number.")?,
+            ),
+            None => return Err("Expected part.samples to be null or a number.".into()),
+        };
+
+        Ok(PartTiming {
+            part: part_from_json(json, "part")?,
+            nanos,
+            samples,
+            status: PartStatus::from_json(json, "part")?,
+            alloc: AllocStats::from_json(json, "part")?,
         })
     }
 }
@@ -186,37 +617,176 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{
+        day,
+        template::{report::PartStatus, Day},
+    };
+
+    use super::{PartTiming, Timing, Timings};
 
-    use super::{Timing, Timings};
+    /// Creates the timing of a day with the given durations of solved parts.
+    fn timing(day: Day, part_1: Option<f64>, part_2: Option<f64>) -> Timing {
+        let parts = [(1, part_1), (2, part_2)]
+            .into_iter()
+            .filter_map(|(part, nanos)| {
+                Some(PartTiming {
+                    part,
+                    nanos: nanos?,
+                    samples: Some(10),
+                    status: PartStatus::Solved,
+                    alloc: None,
+                })
+            })
+            .collect();
+
+        Timing { day, parts }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
+                timing(day!(1), Some(1e+7), Some(2e+7)),
+                timing(day!(2), Some(3e+7), Some(4e+7)),
+                timing(day!(4), Some(4e+7), None),
             ],
+            history: vec![],
+        }
+    }
+
+    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+    macro_rules! assert_approx_eq {
+        ($a:expr, $b:expr) => {{
+            let (a, b) = (&$a, &$b);
+            assert!(
+                (*a - *b).abs() < 1.0e-6,
+                "{} is not approximately equal to {}",
+                *a,
+                *b
+            );
+        }};
+    }
+
+    mod from_reports {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                heap::AllocStats,
+                report::{PartReport, PartStatus},
+                runner::BenchStats,
+                timings::{format_nanos, Timing},
+                Day,
+            },
+        };
+
+        fn report(day: Day, part: u8, answer: Option<&str>, nanos: u64) -> PartReport {
+            let stats = BenchStats::from_samples(&[Duration::from_nanos(nanos)]).unwrap();
+            PartReport::new(day, part, answer, stats)
+        }
+
+        #[test]
+        fn parses_execution_times() {
+            let res = Timing::from_reports(
+                &[
+                    report(day!(1), 1, Some("0"), 74),
+                    report(day!(1), 2, Some("10"), 74_130_000),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos(), 74130074_f64);
+            assert_eq!(format_nanos(res.part_nanos(1).unwrap()), "74.0ns");
+            assert_eq!(format_nanos(res.part_nanos(2).unwrap()), "74.1ms");
+            assert_eq!(res.part(1).unwrap().samples, Some(1));
+        }
+
+        #[test]
+        fn parses_with_patterns_in_answer() {
+            let res = Timing::from_reports(
+                &[
+                    report(
+                        day!(1),
+                        1,
+                        Some("@ @ @ ( ) ms (2s @ 5 samples)"),
+                        2_000_000_000,
+                    ),
+                    report(day!(1), 2, Some("#..#\n.##."), 100_000_000),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos(), 2100000000_f64);
+            assert_eq!(format_nanos(res.part_nanos(1).unwrap()), "2.0s");
+            assert_eq!(format_nanos(res.part_nanos(2).unwrap()), "100.0ms");
+        }
+
+        #[test]
+        fn parses_missing_parts() {
+            let res = Timing::from_reports(
+                &[report(day!(1), 1, None, 10), report(day!(1), 2, None, 10)],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos(), 0_f64);
+            assert_eq!(res.part_nanos(1).is_none(), true);
+            assert_eq!(res.part_nanos(2).is_none(), true);
+        }
+
+        #[test]
+        fn records_timeouts() {
+            let res = Timing::from_reports(
+                &[
+                    report(day!(1), 1, Some("1"), 10),
+                    PartReport::failed(day!(1), 2, PartStatus::TimedOut, Duration::from_secs(10)),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos(), 10_f64);
+            assert_eq!(res.part_nanos(2), None);
+            assert_eq!(res.failure(1), None);
+            assert_eq!(res.failure(2), Some(&PartStatus::TimedOut));
+        }
+
+        #[test]
+        fn records_panics() {
+            let status = PartStatus::Panicked {
+                message: "Expected pair".into(),
+                location: Some("src/bin/2024-05.rs:12:42".into()),
+            };
+            let res = Timing::from_reports(
+                &[PartReport::failed(
+                    day!(1),
+                    1,
+                    status.clone(),
+                    Duration::from_millis(1),
+                )],
+                day!(1),
+            );
+            assert_eq!(res.part_nanos(1), None);
+            assert_eq!(res.failure(1), Some(&status));
+        }
+
+        #[test]
+        fn records_allocations() {
+            let stats = AllocStats {
+                allocations: 12,
+                bytes: 4096,
+                peak_bytes: 1024,
+            };
+            let mut counted = report(day!(1), 1, Some("0"), 74);
+            counted.alloc = Some(stats);
+
+            let res = Timing::from_reports(&[counted, report(day!(1), 2, Some("10"), 74)], day!(1));
+            assert_eq!(res.allocations(1), Some(&stats));
+            assert_eq!(res.allocations(2), None);
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use tinyjson::JsonValue;
+
+        use crate::{
+            day,
+            template::{report::PartStatus, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -225,9 +795,109 @@ mod tests {
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
-            assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.part_nanos(1), Some(1_000_000_f64));
+            assert_eq!(timing.part(1).unwrap().samples, None);
+            assert_eq!(timing.part(2), None);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_json_history() {
+            let json = r#"{ "version": 2, "data": [], "history": [{ "timestamp": 1733011200, "commit": "00c1b8f", "data": [{ "day": "06", "parts": [{ "part": 1, "nanos": 928800, "samples": 5384, "status": "solved" }] }] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 1);
+            let run = timings.history.first().unwrap();
+            assert_eq!(run.timestamp, 1_733_011_200);
+            assert_eq!(run.commit, Some("00c1b8f".to_string()));
+            assert!(run.is_at_commit("00c1b8f1234"));
+            assert!(!run.is_at_commit("00c1b9"));
+            assert_eq!(run.data[0].day, day!(6));
+            assert_eq!(run.environment, None);
+        }
+
+        #[test]
+        fn handles_json_environment() {
+            let json = r#"{ "version": 2, "data": [], "history": [{ "timestamp": 1733011200, "commit": "00c1b8f", "environment": { "cpu": "Apple M1", "cores": 8, "rustc": "1.83.0 (90b35a623 2024-11-26)", "profile": "release", "features": ["dhat-heap"] }, "data": [] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let environment = timings.history[0].environment.as_ref().unwrap();
+            assert_eq!(environment.cpu, Some("Apple M1".into()));
+            assert_eq!(environment.cores, Some(8));
+            assert_eq!(environment.features, ["dhat-heap"]);
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert_eq!(
+                Timings::try_from(json).unwrap().history[0].environment,
+                timings.history[0].environment
+            );
+        }
+
+        #[test]
+        fn handles_json_failures() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parts": [{ "part": 1, "nanos": 1000000, "samples": 3, "status": "solved" }, { "part": 2, "nanos": 0, "samples": 1, "status": "timed_out" }] }], "history": [] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].failure(2), Some(&PartStatus::TimedOut));
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert_eq!(Timings::try_from(json).unwrap().data, timings.data);
+        }
+
+        #[test]
+        fn handles_json_allocations() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parts": [{ "part": 1, "nanos": 1000000, "samples": 3, "status": "solved", "allocations": 12, "alloc_bytes": 4096, "peak_bytes": 1024 }] }], "history": [] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].allocations(1).unwrap();
+            assert_eq!(stats.allocations, 12);
+            assert_eq!(stats.bytes, 4096);
+            assert_eq!(stats.peak_bytes, 1024);
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert_eq!(Timings::try_from(json).unwrap().data, timings.data);
+        }
+
+        #[test]
+        fn handles_json_v2() {
+            let json = r#"{ "version": 2, "data": [{ "day": "06", "parts": [{ "part": 2, "nanos": 589200000, "samples": 17, "status": "solved" }, { "part": 1, "nanos": 928800, "samples": 5384, "status": "solved", "allocations": 3, "alloc_bytes": 96, "peak_bytes": 64 }] }], "history": [{ "timestamp": 1733011200, "commit": null, "data": [{ "day": "06", "parts": [{ "part": 1, "nanos": 10000000000, "samples": 1, "status": "timed_out" }] }] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+
+            let timing = &timings.data[0];
+            assert_eq!(timing.parts[0].part, 1);
+            assert_eq!(timing.part_nanos(1), Some(928_800_f64));
+            assert_eq!(timing.part(1).unwrap().samples, Some(5384));
+            assert_eq!(timing.allocations(1).unwrap().allocations, 3);
+            assert_eq!(timing.part_nanos(2), Some(589_200_000_f64));
+            assert_eq!(timing.allocations(2), None);
+
+            let run = &timings.history[0].data[0];
+            assert_eq!(run.part_nanos(1), None);
+            assert_eq!(run.failure(1), Some(&PartStatus::TimedOut));
+        }
+
+        #[test]
+        fn migrates_json_v1() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "774.1µs", "part_2": "1.2s", "total_nanos": 1200774100 }, { "day": "02", "part_1": "74.0ns", "part_2": null, "total_nanos": 74 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+
+            let timing = &timings.data[0];
+            assert_eq!(timing.part_nanos(1), Some(774_100_f64));
+            assert_eq!(timing.part_nanos(2), Some(1_200_000_000_f64));
+            assert_eq!(timing.part(1).unwrap().samples, None);
+
+            let timing = &timings.data[1];
+            assert_eq!(timing.part_nanos(1), Some(74_f64));
+            assert_eq!(timing.part(2), None);
+            assert!(timings.history.is_empty());
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert!(json.contains(r#""version":2"#));
+            let migrated = Timings::try_from(json).unwrap();
+            assert_eq!(migrated.data, timings.data);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unsupported_version() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
         }
 
         #[test]
@@ -275,75 +945,93 @@ mod tests {
         }
     }
 
+    mod parse_duration {
+        use crate::template::timings::{format_nanos, parse_duration};
+
+        #[test]
+        fn parses_formatted_durations() {
+            assert_eq!(parse_duration("774.1µs"), Some(774_100_f64));
+            assert_eq!(parse_duration("1.2s"), Some(1_200_000_000_f64));
+            assert_eq!(parse_duration("74.0ns"), Some(74_f64));
+            assert_eq!(parse_duration("589.2ms"), Some(589_200_000_f64));
+            assert_eq!(parse_duration("-"), None);
+        }
+
+        #[test]
+        fn formats_nanos() {
+            assert_eq!(format_nanos(774_100_f64), "774.1µs");
+            assert_eq!(format_nanos(589_200_000_f64), "589.2ms");
+        }
+    }
+
     mod is_day_complete {
-        use crate::{
-            day,
-            template::timings::{Timing, Timings},
-        };
+        use crate::{day, template::timings::Timings};
+
+        use super::timing;
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some(1e+6), Some(2e+6))],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some(1e+6), None)],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![timing(day!(1), None, None)],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_benched_parts() {
+            let timings = Timings {
+                data: vec![timing(day!(1), Some(1e+6), None)],
+                history: vec![],
+            };
+
+            assert_eq!(timings.is_part_benched(day!(1), 1), true);
+            assert_eq!(timings.is_part_benched(day!(1), 2), false);
+            assert_eq!(timings.is_part_benched(day!(2), 1), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                report::PartStatus,
+                timings::{MergePolicy, Timings},
+            },
+            year,
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, timing};
 
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(3), None, None)],
+                history: vec![],
             };
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest, year!(2024));
             assert_eq!(merged.data.len(), 4);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
@@ -356,19 +1044,16 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(2), Some(1e+6), None)],
+                history: vec![],
             };
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest, year!(2024));
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].part_nanos(1), Some(1e+6));
+            assert_eq!(merged.data[1].part_nanos(2), Some(4e+7));
             assert_eq!(merged.data[2].day, day!(4));
         }
 
@@ -376,7 +1061,7 @@ mod tests {
         fn handles_empty_timings() {
             let timings = Timings::default();
             let other = get_mock_timings();
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest, year!(2024));
             assert_eq!(merged.data.len(), 3);
         }
 
@@ -384,8 +1069,95 @@ mod tests {
         fn handles_empty_other_timings() {
             let timings = get_mock_timings();
             let other = Timings::default();
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest, year!(2024));
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn keeps_parts_that_did_not_run() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![timing(day!(4), None, Some(5e+7))],
+                history: vec![],
+            };
+
+            for policy in [MergePolicy::Latest, MergePolicy::Best] {
+                let merged = timings.merge(&other, policy, year!(2024));
+                assert_eq!(merged.data[2].part_nanos(1), Some(4e+7));
+                assert_eq!(merged.data[2].part_nanos(2), Some(5e+7));
+                assert!(merged.is_day_complete(day!(4)));
+            }
+        }
+
+        #[test]
+        fn keeps_durations_of_failed_parts() {
+            let timings = get_mock_timings();
+            let mut failed = timing(day!(2), Some(2e+7), Some(1e+10));
+            failed.parts[1].status = PartStatus::TimedOut;
+            let other = Timings {
+                data: vec![failed, timing(day!(3), None, None)],
+                history: vec![],
+            };
+
+            let merged = timings.merge(&other, MergePolicy::Latest, year!(2024));
+            assert_eq!(merged.data[1].part_nanos(1), Some(2e+7));
+            assert_eq!(merged.data[1].part_nanos(2), Some(4e+7));
+            assert_eq!(merged.data[1].failure(2), None);
+            assert!(merged.is_day_complete(day!(2)));
+        }
+
+        #[test]
+        fn keeps_best_durations() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![timing(day!(1), Some(5e+6), Some(3e+7))],
+                history: vec![],
+            };
+
+            let merged = timings.merge(&other, MergePolicy::Best, year!(2024));
+            assert_eq!(merged.data[0].part_nanos(1), Some(5e+6));
+            assert_eq!(merged.data[0].part_nanos(2), Some(2e+7));
+
+            let merged = timings.merge(&other, MergePolicy::Latest, year!(2024));
+            assert_eq!(merged.data[0].part_nanos(2), Some(3e+7));
+        }
+
+        #[test]
+        fn keeps_complete_days_only() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![
+                    timing(day!(1), Some(5e+6), None),
+                    timing(day!(2), Some(5e+6), Some(5e+6)),
+                    timing(day!(3), Some(5e+6), None),
+                ],
+                history: vec![],
+            };
+
+            let merged = timings.merge(&other, MergePolicy::Complete, year!(2024));
+            assert_eq!(merged.data[0].part_nanos(1), Some(1e+7));
+            assert_eq!(merged.data[1].total_nanos(), 1e+7);
+            assert_eq!(merged.data[2].part_nanos(1), Some(5e+6));
+        }
+
+        #[test]
+        fn completes_single_part_days() {
+            let stored = timing(day!(25), Some(1e+7), None);
+            let new = timing(day!(25), Some(5e+6), None);
+
+            let merged = stored.merge(&new, MergePolicy::Complete, &[1]);
+            assert_eq!(merged.part_nanos(1), Some(5e+6));
+
+            let merged = stored.merge(&new, MergePolicy::Complete, &[1, 2]);
+            assert_eq!(merged.part_nanos(1), Some(1e+7));
+        }
+
+        #[test]
+        fn parses_policies() {
+            assert_eq!("latest".parse(), Ok(MergePolicy::Latest));
+            assert_eq!("best".parse(), Ok(MergePolicy::Best));
+            assert_eq!("complete".parse(), Ok(MergePolicy::Complete));
+            assert!("fastest".parse::<MergePolicy>().is_err());
+        }
     }
 }
@@ -0,0 +1,148 @@
+/// Detects changes to the files of a solution by polling their modification times.
+/// Used by `solve --watch`, which avoids depending on platform specific file system events.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{data_dir, Day, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Snapshot = HashMap<PathBuf, SystemTime>;
+
+/// Watches the module of a day, the shared library and the day's inputs and examples.
+pub struct Watcher {
+    year: Year,
+    day: Day,
+    snapshot: Snapshot,
+}
+
+impl Watcher {
+    pub fn new(year: Year, day: Day) -> Self {
+        Self {
+            year,
+            day,
+            snapshot: take_snapshot(&watched_files(year, day)),
+        }
+    }
+
+    /// Blocks until a watched file is created, modified or removed.
+    /// Returns the paths that changed.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let snapshot = take_snapshot(&watched_files(self.year, self.day));
+            let changed = changed_paths(&self.snapshot, &snapshot);
+
+            if !changed.is_empty() {
+                self.snapshot = snapshot;
+                return changed;
+            }
+        }
+    }
+}
+
+/// Returns the files that are watched for a day: the shared library in `src/` (without other solutions),
+/// the module of the day and its input and example files, e.g. `data/2024/examples/01-2.txt`.
+fn watched_files(year: Year, day: Day) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(format!("src/bin/{year}-{day}.rs"))];
+
+    collect_files(Path::new("src"), &mut files, &|path| {
+        !path.starts_with("src/bin")
+    });
+
+    for folder in ["inputs", "examples"] {
+        collect_files(&data_dir(year).join(folder), &mut files, &|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&day.to_string()) && name.ends_with(".txt"))
+        });
+    }
+
+    files
+}
+
+/// Recursively collects the files of a directory that match `filter`.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>, filter: &dyn Fn(&Path) -> bool) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if !filter(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files, filter);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+fn take_snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
+
+/// Returns the sorted paths that were added, removed or modified between two snapshots.
+fn changed_paths(previous: &Snapshot, current: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = current
+        .iter()
+        .filter(|(path, modified)| previous.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .chain(
+            previous
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .cloned(),
+        )
+        .collect();
+
+    changed.sort_unstable();
+    changed
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{changed_paths, Snapshot};
+
+    #[test]
+    fn detects_changed_paths() {
+        let t = SystemTime::UNIX_EPOCH;
+        let later = t + Duration::from_secs(1);
+
+        let previous: Snapshot = [("a", t), ("b", t), ("c", t)]
+            .into_iter()
+            .map(|(p, m)| (PathBuf::from(p), m))
+            .collect();
+
+        let current: Snapshot = [("a", t), ("b", later), ("d", t)]
+            .into_iter()
+            .map(|(p, m)| (PathBuf::from(p), m))
+            .collect();
+
+        assert_eq!(
+            changed_paths(&previous, &current),
+            vec![PathBuf::from("b"), PathBuf::from("c"), PathBuf::from("d")]
+        );
+        assert!(changed_paths(&current, &current).is_empty());
+    }
+}
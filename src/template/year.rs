@@ -0,0 +1,116 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The year of the first Advent of Code event.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid Advent of Code event year (i.e. an integer of 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the year from the `AOC_YEAR` environment variable.
+    /// Returns [`None`] if the variable is not set or not a valid year.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Some(Year(2024)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+        assert!("abc".parse::<Year>().is_err());
+    }
+}